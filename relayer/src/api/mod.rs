@@ -1,12 +1,13 @@
 //! HTTP API for health checks, status, and monitoring
 
-use crate::chain::ChainManager;
+use crate::chain::{ChainManager, EndpointStatus};
 use crate::config::ApiConfig;
+use crate::coordination::PriorityQueue;
 use crate::error::RelayerResult;
-use crate::state::StateManager;
+use crate::state::{RelayedStatus, StateManager};
 
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
     routing::get,
@@ -21,6 +22,7 @@ use tracing::info;
 pub struct AppState {
     pub state_manager: Arc<StateManager>,
     pub chain_manager: Arc<ChainManager>,
+    pub priority_queue: Arc<PriorityQueue>,
 }
 
 /// Run the HTTP API server
@@ -28,10 +30,12 @@ pub async fn run_server(
     config: ApiConfig,
     state_manager: Arc<StateManager>,
     chain_manager: Arc<ChainManager>,
+    priority_queue: Arc<PriorityQueue>,
 ) -> RelayerResult<()> {
     let state = AppState {
         state_manager,
         chain_manager,
+        priority_queue,
     };
 
     let app = Router::new()
@@ -40,6 +44,8 @@ pub async fn run_server(
         .route("/status", get(get_status))
         .route("/chains", get(get_chains))
         .route("/stats", get(get_stats))
+        .route("/relayed/:relayed_id", get(get_relayed_status))
+        .route("/metrics", get(crate::metrics::metrics_handler))
         .with_state(state);
 
     let addr = format!("{}:{}", config.host, config.port);
@@ -107,6 +113,19 @@ async fn readiness_check(State(state): State<AppState>) -> impl IntoResponse {
 async fn get_status(State(state): State<AppState>) -> impl IntoResponse {
     let chain_health = state.chain_manager.health_check().await;
 
+    let rpc_endpoints = state
+        .chain_manager
+        .connected_chains()
+        .into_iter()
+        .filter_map(|chain_id| {
+            let provider = state.chain_manager.get_provider(chain_id).ok()?;
+            Some(ChainEndpoints {
+                chain_id,
+                endpoints: provider.endpoint_status(),
+            })
+        })
+        .collect();
+
     Json(StatusResponse {
         version: env!("CARGO_PKG_VERSION").to_string(),
         uptime_seconds: 0, // Would track actual uptime
@@ -118,6 +137,7 @@ async fn get_status(State(state): State<AppState>) -> impl IntoResponse {
                 healthy: h,
             })
             .collect(),
+        rpc_endpoints,
     })
 }
 
@@ -129,6 +149,17 @@ async fn get_chains(State(state): State<AppState>) -> impl IntoResponse {
 
 /// Get transaction statistics
 async fn get_stats(State(state): State<AppState>) -> impl IntoResponse {
+    let mut queues = Vec::new();
+    for chain_id in state.chain_manager.connected_chains() {
+        let q = state.priority_queue.stats(chain_id).await;
+        queues.push(ChainQueueStats {
+            chain_id,
+            ready: q.ready,
+            future: q.future,
+            evicted_total: q.evicted_total,
+        });
+    }
+
     match state.state_manager.get_stats().await {
         Ok(stats) => (
             StatusCode::OK,
@@ -138,6 +169,7 @@ async fn get_stats(State(state): State<AppState>) -> impl IntoResponse {
                 submitted: stats.submitted,
                 finalized: stats.finalized,
                 failed: stats.failed,
+                queues,
             }),
         ),
         Err(_) => (
@@ -148,11 +180,48 @@ async fn get_stats(State(state): State<AppState>) -> impl IntoResponse {
                 submitted: 0,
                 finalized: 0,
                 failed: 0,
+                queues,
             }),
         ),
     }
 }
 
+/// Look up a forced (L1-relayed) transaction's status by its hex-encoded
+/// `relayed_id` (with or without a `0x` prefix)
+async fn get_relayed_status(
+    State(state): State<AppState>,
+    Path(relayed_id): Path<String>,
+) -> (StatusCode, Json<RelayedStatusResponse>) {
+    let Ok(decoded) = hex::decode(relayed_id.trim_start_matches("0x")) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(RelayedStatusResponse::error("relayed_id is not valid hex")),
+        );
+    };
+
+    if decoded.len() != 32 {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(RelayedStatusResponse::error("relayed_id must be 32 bytes")),
+        );
+    }
+
+    let mut relayed_id_bytes = [0u8; 32];
+    relayed_id_bytes.copy_from_slice(&decoded);
+
+    match state.state_manager.relayed_transaction_status(&relayed_id_bytes).await {
+        Ok(RelayedStatus::NotFound) => (
+            StatusCode::NOT_FOUND,
+            Json(RelayedStatusResponse::error("no forced transaction with this relayed_id")),
+        ),
+        Ok(status) => (StatusCode::OK, Json(RelayedStatusResponse::from_status(status))),
+        Err(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(RelayedStatusResponse::error("failed to look up status")),
+        ),
+    }
+}
+
 // Response types
 
 #[derive(Serialize)]
@@ -181,6 +250,13 @@ struct StatusResponse {
     uptime_seconds: u64,
     connected_chains: Vec<u64>,
     chain_status: Vec<ChainHealth>,
+    rpc_endpoints: Vec<ChainEndpoints>,
+}
+
+#[derive(Serialize)]
+struct ChainEndpoints {
+    chain_id: u64,
+    endpoints: Vec<EndpointStatus>,
 }
 
 #[derive(Serialize)]
@@ -195,4 +271,44 @@ struct StatsResponse {
     submitted: u64,
     finalized: u64,
     failed: u64,
+    queues: Vec<ChainQueueStats>,
+}
+
+#[derive(Serialize)]
+struct ChainQueueStats {
+    chain_id: u64,
+    ready: usize,
+    future: usize,
+    evicted_total: u64,
+}
+
+#[derive(Serialize)]
+struct RelayedStatusResponse {
+    status: String,
+    reason: Option<String>,
+    error: Option<String>,
+}
+
+impl RelayedStatusResponse {
+    fn from_status(status: RelayedStatus) -> Self {
+        let (status, reason) = match status {
+            RelayedStatus::NotFound => ("not_found".to_string(), None),
+            RelayedStatus::Pending => ("pending".to_string(), None),
+            RelayedStatus::Executed => ("executed".to_string(), None),
+            RelayedStatus::Failed { reason } => ("failed".to_string(), Some(reason)),
+        };
+        Self {
+            status,
+            reason,
+            error: None,
+        }
+    }
+
+    fn error(message: &str) -> Self {
+        Self {
+            status: "error".to_string(),
+            reason: None,
+            error: Some(message.to_string()),
+        }
+    }
 }
@@ -0,0 +1,84 @@
+//! In-flight dedup for concurrent event processing
+//!
+//! Multi-RPC failover and WebSocket-plus-HTTP fallback can both observe the
+//! same log (e.g. a WS stream delivers it while a reconnect-triggered
+//! catch-up scan re-fetches the same range), which would otherwise cause
+//! duplicate DB writes and double-coordination. `ProcessMap` keys in-flight
+//! processing by `(chain_id, tx_hash, log_index)`: the first caller for a key
+//! runs the handler, and any concurrent caller for the same key waits for
+//! that run to finish and receives the same outcome instead of re-running
+//! it. Combined with the unique `(chain_id, tx_hash, log_index)` constraint
+//! on `contract_events`, processing stays exactly-once even across restarts,
+//! where this in-memory map starts out empty.
+
+use crate::error::{RelayerError, RelayerResult};
+
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use ethers::types::H256;
+use std::future::Future;
+use tokio::sync::broadcast;
+use tracing::debug;
+
+/// Identifies a single log for dedup purposes
+pub type ProcessKey = (u64, H256, u64);
+
+/// Tracks in-flight log processing so concurrent deliveries of the same log
+/// collapse into a single run of the handler
+pub struct ProcessMap {
+    in_flight: DashMap<ProcessKey, broadcast::Sender<Result<(), String>>>,
+}
+
+impl ProcessMap {
+    pub fn new() -> Self {
+        Self {
+            in_flight: DashMap::new(),
+        }
+    }
+
+    /// Run `f` for `key` unless another caller is already processing it, in
+    /// which case wait for that run and return its result instead
+    pub async fn dedup<F, Fut>(&self, key: ProcessKey, f: F) -> RelayerResult<()>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = RelayerResult<()>>,
+    {
+        let mut follower = None;
+
+        match self.in_flight.entry(key) {
+            Entry::Occupied(entry) => {
+                follower = Some(entry.get().subscribe());
+            }
+            Entry::Vacant(entry) => {
+                let (tx, _rx) = broadcast::channel(1);
+                entry.insert(tx);
+            }
+        }
+
+        if let Some(mut rx) = follower {
+            debug!("Chain {} tx {:?}: awaiting in-flight processing of log {}", key.0, key.1, key.2);
+            return match rx.recv().await {
+                Ok(result) => result.map_err(RelayerError::Internal),
+                // Leader's sender was dropped without sending, meaning it
+                // panicked mid-processing; nothing more we can do for this
+                // caller than let it move on.
+                Err(_) => Ok(()),
+            };
+        }
+
+        let result = f().await;
+
+        if let Some((_, tx)) = self.in_flight.remove(&key) {
+            let broadcast_result = result.as_ref().map(|_| ()).map_err(|e| e.to_string());
+            let _ = tx.send(broadcast_result);
+        }
+
+        result
+    }
+}
+
+impl Default for ProcessMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,60 +1,380 @@
 //! Chain finality tracking for different L1/L2 networks
 //!
 //! Different chains have different finality models:
-//! - Ethereum: Probabilistic (32 blocks for practical finality, ~6 min)
+//! - Ethereum: Probabilistic pre-merge, consensus-finalized post-merge (~2 epochs, ~13 min)
 //! - Polygon: Probabilistic (128 blocks)
 //! - Arbitrum: L1 finality (challenge period ~7 days for full, but we use soft finality)
 //! - Optimism: L1 finality (same as Arbitrum)
 //! - Avalanche: Instant finality (1 block)
+//!
+//! How a chain decides "finalized" is pluggable via [`FinalityBackend`],
+//! selected per chain through `ChainConfig::finality_backend`:
+//! - [`ConfirmationCountBackend`] approximates finality by waiting a fixed
+//!   number of blocks past inclusion — the only option for chains with no
+//!   notion of an irreversible checkpoint.
+//! - [`ConsensusFinalizedBackend`] asks the node directly for its
+//!   `finalized` checkpoint via `eth_getBlockByNumber`, which by
+//!   definition cannot reorg and avoids both the arbitrary wait and spurious
+//!   reorg errors on transactions the consensus layer already finalized.
+//! - [`L1AnchoredFinalityBackend`] is for optimistic rollups: neither block
+//!   depth nor the rollup's own checkpoint means anything if the parent
+//!   chain reorgs the batch they were built from, so this instead reads the
+//!   rollup's batch-posting contract on L1, as of L1's own `finalized` tag.
+//!
+//! Common-ancestor reorg detection itself lives in `ChainListener` (block
+//! hash ring buffer, event rollback) and `ConfirmationTracker` (per-claim
+//! canonical-block recheck before marking a resolve `Finalized`), since both
+//! already hold the provider and state needed to act on a reorg. This
+//! tracker instead anchors each tracked transaction to the hash of its
+//! inclusion block and re-checks that hash before declaring finality, which
+//! catches a reorg underneath the tx even when the receipt itself still
+//! resolves (`tesseract_reorgs_total` / `tesseract_reorg_depth`).
 
 use crate::chain::ChainProvider;
+use crate::config::FinalityBackendKind;
 use crate::error::{RelayerError, RelayerResult};
+use crate::state::StateManager;
 
-use ethers::types::H256;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ethers::types::{Address, BlockNumber, H256, U256};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
+/// A pluggable strategy for deciding whether a block has been finalized.
+///
+/// Implementations only need to answer "has `block_number` been finalized
+/// yet", given whatever chain state they need to fetch to do so;
+/// `FinalityTracker` owns the pending/finalized bookkeeping and reorg
+/// protection on top.
+#[async_trait]
+pub trait FinalityBackend: Send + Sync {
+    /// Whether `block_number` is considered finalized right now.
+    async fn is_finalized(&self, block_number: u64) -> RelayerResult<bool>;
+
+    /// Short human-readable description of the threshold, for logs.
+    fn describe(&self) -> String;
+}
+
+/// Waits a fixed number of confirmation blocks past inclusion. The
+/// traditional approach, and the only one available on chains with no
+/// consensus-level finality checkpoint.
+pub struct ConfirmationCountBackend {
+    provider: Arc<ChainProvider>,
+    confirmation_blocks: u64,
+}
+
+#[async_trait]
+impl FinalityBackend for ConfirmationCountBackend {
+    async fn is_finalized(&self, block_number: u64) -> RelayerResult<bool> {
+        let current_block = self.provider.get_block_number().await?;
+        Ok(current_block.saturating_sub(block_number) >= self.confirmation_blocks)
+    }
+
+    fn describe(&self) -> String {
+        format!("{} confirmation blocks", self.confirmation_blocks)
+    }
+}
+
+/// Reads the node's consensus-finalized checkpoint (the `finalized` or
+/// `safe` block tag) and treats anything at or below it as finalized. That
+/// checkpoint cannot reorg by definition, so this both removes the wait for
+/// an arbitrary confirmation depth and eliminates false reorg errors on
+/// transactions the consensus layer has already finalized.
+pub struct ConsensusFinalizedBackend {
+    provider: Arc<ChainProvider>,
+    tag: BlockNumber,
+}
+
+#[async_trait]
+impl FinalityBackend for ConsensusFinalizedBackend {
+    async fn is_finalized(&self, block_number: u64) -> RelayerResult<bool> {
+        let finalized_block = self.provider.get_block_number_by_tag(self.tag).await?;
+        Ok(block_number <= finalized_block)
+    }
+
+    fn describe(&self) -> String {
+        format!("consensus `{:?}` checkpoint", self.tag)
+    }
+}
+
+/// Parent-chain wiring an [`L1AnchoredFinalityBackend`] needs: the L1
+/// provider to query, which contract on it tracks this rollup's confirmed
+/// L2 state, and which zero-argument view to call to read it.
+pub struct L1AnchorConfig {
+    pub l1_provider: Arc<ChainProvider>,
+    pub batch_contract_address: String,
+    pub confirmed_block_selector: String,
+}
+
+/// Optimistic-rollup finality: trusts the parent (L1) chain's batch-posting
+/// contract rather than the rollup's own block-depth or sequencer
+/// checkpoints, which isn't a meaningful notion of finality for a rollup (a
+/// reorg of the L1 batch invalidates everything built on it regardless of
+/// how "deep" the L2 block looks). The contract's confirmed-L2-block view is
+/// read as of L1's own `finalized` tag, so a single check establishes both
+/// "has this batch been posted" and "is that posting itself irreversible" -
+/// no separate tracking of which L1 block the batch landed in is needed.
+///
+/// The function selector is configured per chain rather than hardcoded to
+/// one ABI, since Arbitrum's `Rollup` and Optimism's `L2OutputOracle` (and
+/// other rollup stacks) expose this differently.
+pub struct L1AnchoredFinalityBackend {
+    l1_provider: Arc<ChainProvider>,
+    batch_contract: Address,
+    confirmed_block_selector: [u8; 4],
+}
+
+#[async_trait]
+impl FinalityBackend for L1AnchoredFinalityBackend {
+    async fn is_finalized(&self, block_number: u64) -> RelayerResult<bool> {
+        let response = self
+            .l1_provider
+            .call_raw(
+                self.batch_contract,
+                self.confirmed_block_selector.to_vec(),
+                BlockNumber::Finalized,
+            )
+            .await?;
+        let confirmed_l2_block = U256::from_big_endian(&response).as_u64();
+        Ok(block_number <= confirmed_l2_block)
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "L1-anchored (contract {:?}, as of L1 finalized)",
+            self.batch_contract
+        )
+    }
+}
+
+/// What we remember about a transaction while it's waiting to finalize: the
+/// block it landed in, plus that block's hash at tracking time so a reorg
+/// underneath it can be detected even while the receipt itself still exists
+/// (e.g. the tx was also included in the reorged-in chain, just deeper or
+/// shallower than before).
+#[derive(Debug, Clone, Copy)]
+struct PendingEntry {
+    block_number: u64,
+    block_hash: Option<H256>,
+    /// When tracking started, so finalization can observe end-to-end
+    /// latency into `tesseract_finality_latency_seconds`.
+    tracked_at: DateTime<Utc>,
+}
+
 /// Tracks finality for transactions on a specific chain
 pub struct FinalityTracker {
     /// Chain ID
     chain_id: u64,
-    /// Required confirmation blocks
-    confirmation_blocks: u64,
+    /// Finality determination strategy for this chain
+    backend: Box<dyn FinalityBackend>,
     /// Chain provider
     provider: Arc<ChainProvider>,
-    /// Pending transactions: tx_hash -> block_number
-    pending: RwLock<HashMap<H256, u64>>,
+    /// Persists anchors so a restart can resume tracking via `restore()`
+    state_manager: Arc<StateManager>,
+    /// Pending transactions: tx_hash -> tracked block anchor
+    pending: RwLock<HashMap<H256, PendingEntry>>,
     /// Finalized transactions (cached to avoid re-checking)
     finalized: RwLock<HashMap<H256, bool>>,
 }
 
 impl FinalityTracker {
-    /// Create a new finality tracker
+    /// Create a new finality tracker. `confirmation_blocks` is only
+    /// consulted when `finality_backend` is `ConfirmationCount`; `l1_anchor`
+    /// is only consulted (and must be `Some`) when it is `L1Anchored`.
     pub fn new(
         chain_id: u64,
         confirmation_blocks: u64,
+        finality_backend: FinalityBackendKind,
         provider: Arc<ChainProvider>,
-    ) -> Self {
-        Self {
+        state_manager: Arc<StateManager>,
+        l1_anchor: Option<L1AnchorConfig>,
+    ) -> RelayerResult<Self> {
+        let backend = Self::build_backend(finality_backend, confirmation_blocks, provider.clone(), l1_anchor)?;
+
+        info!(
+            "Finality tracker for chain {} using backend: {}",
+            chain_id,
+            backend.describe()
+        );
+
+        Ok(Self {
             chain_id,
-            confirmation_blocks,
+            backend,
             provider,
+            state_manager,
             pending: RwLock::new(HashMap::new()),
             finalized: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Rebuild a finality tracker from anchors persisted before a restart,
+    /// so a crash doesn't silently drop transactions `track()` already
+    /// accepted and leave them to the slower untracked-receipt fallback in
+    /// `is_finalized`.
+    pub async fn restore(
+        chain_id: u64,
+        confirmation_blocks: u64,
+        finality_backend: FinalityBackendKind,
+        provider: Arc<ChainProvider>,
+        state_manager: Arc<StateManager>,
+        l1_anchor: Option<L1AnchorConfig>,
+    ) -> RelayerResult<Self> {
+        let backend = Self::build_backend(finality_backend, confirmation_blocks, provider.clone(), l1_anchor)?;
+        let anchors = state_manager.load_finality_anchors(chain_id).await?;
+
+        let mut pending = HashMap::with_capacity(anchors.len());
+        for anchor in &anchors {
+            pending.insert(
+                anchor.tx_hash,
+                PendingEntry {
+                    block_number: anchor.block_number,
+                    block_hash: anchor.block_hash,
+                    tracked_at: anchor.tracked_at,
+                },
+            );
+        }
+        crate::metrics::record_finality_pending(chain_id, pending.len());
+
+        info!(
+            "Restored {} pending finality anchor(s) for chain {} (backend: {})",
+            anchors.len(),
+            chain_id,
+            backend.describe()
+        );
+
+        Ok(Self {
+            chain_id,
+            backend,
+            provider,
+            state_manager,
+            pending: RwLock::new(pending),
+            finalized: RwLock::new(HashMap::new()),
+        })
+    }
+
+    fn build_backend(
+        finality_backend: FinalityBackendKind,
+        confirmation_blocks: u64,
+        provider: Arc<ChainProvider>,
+        l1_anchor: Option<L1AnchorConfig>,
+    ) -> RelayerResult<Box<dyn FinalityBackend>> {
+        match finality_backend {
+            FinalityBackendKind::ConfirmationCount => Ok(Box::new(ConfirmationCountBackend {
+                provider,
+                confirmation_blocks,
+            })),
+            FinalityBackendKind::ConsensusFinalized => Ok(Box::new(ConsensusFinalizedBackend {
+                provider,
+                tag: BlockNumber::Finalized,
+            })),
+            FinalityBackendKind::L1Anchored => {
+                let anchor = l1_anchor.ok_or_else(|| {
+                    RelayerError::Config(
+                        "l1_anchored finality backend selected but no L1 anchor config was resolved"
+                            .to_string(),
+                    )
+                })?;
+
+                let batch_contract = anchor.batch_contract_address.parse::<Address>().map_err(|e| {
+                    RelayerError::Config(format!(
+                        "invalid l1_batch_contract_address {:?}: {}",
+                        anchor.batch_contract_address, e
+                    ))
+                })?;
+
+                let selector_hex = anchor.confirmed_block_selector.trim_start_matches("0x");
+                let selector_bytes = hex::decode(selector_hex).map_err(|e| {
+                    RelayerError::Config(format!(
+                        "invalid l1_confirmed_block_selector {:?}: {}",
+                        anchor.confirmed_block_selector, e
+                    ))
+                })?;
+                let confirmed_block_selector: [u8; 4] = selector_bytes.try_into().map_err(|bytes: Vec<u8>| {
+                    RelayerError::Config(format!(
+                        "l1_confirmed_block_selector must be exactly 4 bytes, got {}",
+                        bytes.len()
+                    ))
+                })?;
+
+                Ok(Box::new(L1AnchoredFinalityBackend {
+                    l1_provider: anchor.l1_provider,
+                    batch_contract,
+                    confirmed_block_selector,
+                }))
+            }
         }
     }
 
-    /// Track a new transaction for finality
+    /// Track a new transaction for finality, anchoring it to the hash of
+    /// `block_number` at tracking time so a later reorg underneath it can be
+    /// detected by comparing against the canonical hash at that height.
+    /// A repeat call for a tx already tracked at the same block keeps its
+    /// original anchor and `tracked_at`.
     pub async fn track(&self, tx_hash: H256, block_number: u64) {
-        self.pending.write().await.insert(tx_hash, block_number);
+        if matches!(
+            self.pending.read().await.get(&tx_hash),
+            Some(entry) if entry.block_number == block_number
+        ) {
+            return;
+        }
+        let block_hash = self.block_hash_at(block_number).await;
+        let tracked_at = Utc::now();
+        let pending_count = {
+            let mut pending = self.pending.write().await;
+            pending.insert(tx_hash, PendingEntry { block_number, block_hash, tracked_at });
+            pending.len()
+        };
+        self.persist_anchor(tx_hash, block_number, block_hash).await;
+        crate::metrics::record_finality_pending(self.chain_id, pending_count);
         debug!(
             "Tracking tx {} for finality on chain {} (block {})",
             tx_hash, self.chain_id, block_number
         );
     }
 
+    /// Best-effort persist of a pending anchor; a failure here only costs a
+    /// slower recovery path after a crash, not correctness, so it's logged
+    /// rather than surfaced to the caller.
+    async fn persist_anchor(&self, tx_hash: H256, block_number: u64, block_hash: Option<H256>) {
+        if let Err(e) = self
+            .state_manager
+            .record_finality_tracking(self.chain_id, tx_hash, block_number, block_hash)
+            .await
+        {
+            warn!(
+                "Failed to persist finality anchor for tx {} on chain {}: {}",
+                tx_hash, self.chain_id, e
+            );
+        }
+    }
+
+    /// Best-effort clear of a finalized anchor; same failure handling as
+    /// `persist_anchor`.
+    async fn clear_anchor(&self, tx_hash: H256) {
+        if let Err(e) = self.state_manager.clear_finality_tracking(self.chain_id, tx_hash).await {
+            warn!(
+                "Failed to clear finality anchor for tx {} on chain {}: {}",
+                tx_hash, self.chain_id, e
+            );
+        }
+    }
+
+    /// Look up the canonical block hash at `block_number`, or `None` if the
+    /// lookup fails (treated as "no anchor to compare against" rather than
+    /// an error, since this is a best-effort reorg signal on top of the
+    /// receipt check that already gates finality).
+    async fn block_hash_at(&self, block_number: u64) -> Option<H256> {
+        self.provider
+            .get_block(block_number)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|b| b.hash)
+    }
+
     /// Check if a transaction has reached finality
     pub async fn is_finalized(&self, tx_hash: H256) -> RelayerResult<bool> {
         // Check cache first
@@ -62,25 +382,27 @@ impl FinalityTracker {
             return Ok(finalized);
         }
 
-        // Get current block
-        let current_block = self.provider.get_block_number().await?;
-
         // Check if we're tracking this tx
         let pending = self.pending.read().await;
-        if let Some(&tx_block) = pending.get(&tx_hash) {
-            let confirmations = current_block.saturating_sub(tx_block);
-
-            if confirmations >= self.confirmation_blocks {
+        if let Some(&entry) = pending.get(&tx_hash) {
+            if self.backend.is_finalized(entry.block_number).await? {
                 // Verify the transaction is still included (reorg protection)
-                if self.verify_inclusion(tx_hash).await? {
+                if self.verify_inclusion(tx_hash, &entry).await? {
                     // Cache the result
                     drop(pending);
                     self.finalized.write().await.insert(tx_hash, true);
-                    self.pending.write().await.remove(&tx_hash);
+                    let pending_count = {
+                        let mut pending = self.pending.write().await;
+                        pending.remove(&tx_hash);
+                        pending.len()
+                    };
+                    self.clear_anchor(tx_hash).await;
+                    self.observe_finality_latency(entry.tracked_at);
+                    crate::metrics::record_finality_pending(self.chain_id, pending_count);
 
                     info!(
-                        "Transaction {} finalized on chain {} ({} confirmations)",
-                        tx_hash, self.chain_id, confirmations
+                        "Transaction {} finalized on chain {} ({})",
+                        tx_hash, self.chain_id, self.backend.describe()
                     );
                     return Ok(true);
                 } else {
@@ -91,14 +413,14 @@ impl FinalityTracker {
                     );
                     return Err(RelayerError::ReorgDetected {
                         chain_id: self.chain_id,
-                        block_number: tx_block,
+                        block_number: entry.block_number,
                     });
                 }
             }
 
             debug!(
-                "Transaction {} has {} / {} confirmations on chain {}",
-                tx_hash, confirmations, self.confirmation_blocks, self.chain_id
+                "Transaction {} not yet finalized on chain {} ({})",
+                tx_hash, self.chain_id, self.backend.describe()
             );
             return Ok(false);
         }
@@ -106,16 +428,24 @@ impl FinalityTracker {
         // Not tracked - try to get info from chain
         if let Some(receipt) = self.provider.get_transaction_receipt(tx_hash).await? {
             if let Some(block_num) = receipt.block_number {
-                let confirmations = current_block.saturating_sub(block_num.as_u64());
-                if confirmations >= self.confirmation_blocks {
+                let block_num = block_num.as_u64();
+                if self.backend.is_finalized(block_num).await? {
                     self.finalized.write().await.insert(tx_hash, true);
                     return Ok(true);
                 } else {
                     // Start tracking
-                    self.pending
-                        .write()
-                        .await
-                        .insert(tx_hash, block_num.as_u64());
+                    let block_hash = self.block_hash_at(block_num).await;
+                    let tracked_at = Utc::now();
+                    let pending_count = {
+                        let mut pending = self.pending.write().await;
+                        pending.insert(
+                            tx_hash,
+                            PendingEntry { block_number: block_num, block_hash, tracked_at },
+                        );
+                        pending.len()
+                    };
+                    self.persist_anchor(tx_hash, block_num, block_hash).await;
+                    crate::metrics::record_finality_pending(self.chain_id, pending_count);
                     return Ok(false);
                 }
             }
@@ -127,12 +457,40 @@ impl FinalityTracker {
         })
     }
 
-    /// Verify a transaction is still included in the chain
-    async fn verify_inclusion(&self, tx_hash: H256) -> RelayerResult<bool> {
-        match self.provider.get_transaction_receipt(tx_hash).await? {
-            Some(receipt) => Ok(receipt.status == Some(1.into())),
-            None => Ok(false),
+    /// Verify a transaction is still included and that the block it was
+    /// anchored to hasn't been replaced by a reorg.
+    async fn verify_inclusion(&self, tx_hash: H256, entry: &PendingEntry) -> RelayerResult<bool> {
+        let included = match self.provider.get_transaction_receipt(tx_hash).await? {
+            Some(receipt) => receipt.status == Some(1.into()),
+            None => false,
+        };
+        if !included {
+            return Ok(false);
+        }
+
+        let Some(anchored_hash) = entry.block_hash else {
+            // No anchor was captured (e.g. the RPC lookup failed at track
+            // time) - fall back to the receipt check alone.
+            return Ok(true);
+        };
+
+        let canonical_hash = self.block_hash_at(entry.block_number).await;
+        if canonical_hash != Some(anchored_hash) {
+            let current_block = self
+                .provider
+                .get_block_number()
+                .await
+                .unwrap_or(entry.block_number);
+            let depth = current_block.saturating_sub(entry.block_number);
+            warn!(
+                "Chain {}: block {} hash changed underneath tx {} (reorg depth {})",
+                self.chain_id, entry.block_number, tx_hash, depth
+            );
+            crate::metrics::record_finality_reorg(self.chain_id, depth);
+            return Ok(false);
         }
+
+        Ok(true)
     }
 
     /// Get pending transaction count
@@ -142,46 +500,79 @@ impl FinalityTracker {
 
     /// Check all pending transactions and return newly finalized ones
     pub async fn check_pending(&self) -> RelayerResult<Vec<H256>> {
-        let current_block = self.provider.get_block_number().await?;
         let mut finalized = Vec::new();
 
         let pending = self.pending.read().await.clone();
-        for (tx_hash, tx_block) in pending {
-            let confirmations = current_block.saturating_sub(tx_block);
-            if confirmations >= self.confirmation_blocks {
-                if self.verify_inclusion(tx_hash).await? {
-                    finalized.push(tx_hash);
-                }
+        for (tx_hash, entry) in pending {
+            if self.backend.is_finalized(entry.block_number).await?
+                && self.verify_inclusion(tx_hash, &entry).await?
+            {
+                finalized.push((tx_hash, entry));
             }
         }
 
         // Update state
-        for tx_hash in &finalized {
-            self.pending.write().await.remove(tx_hash);
+        let pending_count = {
+            let mut pending = self.pending.write().await;
+            for (tx_hash, _) in &finalized {
+                pending.remove(tx_hash);
+            }
+            pending.len()
+        };
+        for (tx_hash, entry) in &finalized {
             self.finalized.write().await.insert(*tx_hash, true);
+            self.clear_anchor(*tx_hash).await;
+            self.observe_finality_latency(entry.tracked_at);
         }
+        crate::metrics::record_finality_pending(self.chain_id, pending_count);
 
-        Ok(finalized)
-    }
-
-    /// Clear old finalized cache entries (call periodically)
-    pub async fn cleanup_cache(&self, max_entries: usize) {
-        let mut finalized = self.finalized.write().await;
-        if finalized.len() > max_entries {
-            // Simple FIFO-ish cleanup - just clear half
-            let to_remove: Vec<_> = finalized
-                .keys()
-                .take(finalized.len() / 2)
-                .cloned()
-                .collect();
-            for k in to_remove {
-                finalized.remove(&k);
-            }
+        Ok(finalized.into_iter().map(|(tx_hash, _)| tx_hash).collect())
+    }
+
+    /// Observe the time from `tracked_at` to now into
+    /// `tesseract_finality_latency_seconds`.
+    fn observe_finality_latency(&self, tracked_at: DateTime<Utc>) {
+        let latency_secs = (Utc::now() - tracked_at).num_milliseconds() as f64 / 1000.0;
+        crate::metrics::record_finality_latency(self.chain_id, latency_secs.max(0.0));
+    }
+
+    /// Evict persisted anchors (and their in-memory pending entries) that
+    /// haven't been touched in `ttl_secs`. Replaces the old in-memory-only
+    /// `cleanup_cache`: now that anchors are durable, a transaction
+    /// abandoned mid-flight (a superseded dependency, a chain outage that
+    /// outlasted retries) needs its own eviction policy or it pins a row in
+    /// `finality_anchors` forever. Returns the number of anchors evicted.
+    pub async fn evict_stale(&self, ttl_secs: i64) -> RelayerResult<u64> {
+        let evicted = self
+            .state_manager
+            .evict_stale_finality_tracking(self.chain_id, ttl_secs)
+            .await?;
+
+        if !evicted.is_empty() {
+            let pending_count = {
+                let mut pending = self.pending.write().await;
+                for tx_hash in &evicted {
+                    pending.remove(tx_hash);
+                }
+                pending.len()
+            };
+            crate::metrics::record_finality_pending(self.chain_id, pending_count);
+            debug!(
+                "Evicted {} stale finality anchor(s) on chain {}",
+                evicted.len(),
+                self.chain_id
+            );
         }
+
+        Ok(evicted.len() as u64)
     }
 }
 
-/// Get recommended confirmation blocks for a chain
+/// Suggested `confirmation_blocks` value for a chain, for use with the
+/// `ConfirmationCount` backend on chains with no consensus finality
+/// checkpoint. Chains that expose one (e.g. post-merge Ethereum, most L2s)
+/// should instead configure the `ConsensusFinalized` backend via
+/// `ChainConfig::finality_backend`, which makes this heuristic unnecessary.
 pub fn recommended_confirmations(chain_id: u64) -> u64 {
     match chain_id {
         // Ethereum mainnet
@@ -5,15 +5,25 @@ use crate::error::{RelayerError, RelayerResult};
 use crate::events::{ContractEvent, EventParser};
 use crate::state::StateManager;
 
+use super::dedup::ProcessMap;
 use super::ChainProvider;
 
 use ethers::prelude::*;
+use futures::StreamExt;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::broadcast;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
+/// Ceiling for the exponential reconnect backoff, matching the HTTP polling interval
+const WS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How many recently processed (block_number, block_hash) pairs to retain
+/// for reorg detection. Comfortably larger than any realistic reorg depth.
+const BLOCK_HASH_BUFFER_SIZE: usize = 256;
+
 /// Listens for contract events on a specific chain
 pub struct ChainListener {
     /// Chain configuration
@@ -24,8 +34,19 @@ pub struct ChainListener {
     event_tx: broadcast::Sender<ContractEvent>,
     /// State manager for checkpoint persistence
     state_manager: Arc<StateManager>,
-    /// Last processed block
+    /// Furthest block we've scanned for logs - may be ahead of the
+    /// persisted checkpoint while it sits in the unconfirmed tip
     last_processed_block: RwLock<u64>,
+    /// Furthest block persisted as the checkpoint. Lags `last_processed_block`
+    /// by up to `confirmation_blocks` so a reorg in the unconfirmed tip can be
+    /// corrected without losing already-finalized progress.
+    checkpointed_block: RwLock<u64>,
+    /// Recently processed (block_number, block_hash) pairs, oldest first,
+    /// used to detect when the chain has reorged underneath us
+    block_hashes: RwLock<VecDeque<(u64, H256)>>,
+    /// Collapses concurrent deliveries of the same log (e.g. a WS stream and
+    /// a catch-up poll both observing it) into a single processing run
+    process_map: ProcessMap,
     /// Event parser
     event_parser: EventParser,
 }
@@ -52,6 +73,9 @@ impl ChainListener {
             event_tx,
             state_manager,
             last_processed_block: RwLock::new(last_block),
+            checkpointed_block: RwLock::new(last_block),
+            block_hashes: RwLock::new(VecDeque::new()),
+            process_map: ProcessMap::new(),
             event_parser,
         })
     }
@@ -74,6 +98,12 @@ impl ChainListener {
     }
 
     /// WebSocket-based event listening
+    ///
+    /// Streams logs via `subscribe_logs` instead of polling. Each subscription
+    /// resumes from the block after the last processed one, so a dropped
+    /// socket or RPC error just triggers a re-subscribe (with exponential
+    /// backoff) rather than losing progress or replaying already-processed
+    /// blocks.
     async fn listen_ws(&self) -> RelayerResult<()> {
         let contract_address: Address = self
             .config
@@ -81,14 +111,299 @@ impl ChainListener {
             .parse()
             .map_err(|e| RelayerError::Config(format!("Invalid contract address: {}", e)))?;
 
-        // Create event filter
+        let mut backoff = Duration::from_secs(1);
+
+        loop {
+            let Some(ws) = self.provider.ws().await else {
+                warn!(
+                    "Chain {} has no WebSocket provider, falling back to polling",
+                    self.config.chain_id
+                );
+                return self.listen_polling().await;
+            };
+
+            let from_block = *self.last_processed_block.read().await + 1;
+            let filter = Filter::new()
+                .address(contract_address)
+                .from_block(from_block);
+
+            let mut stream = match ws.subscribe_logs(&filter).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!(
+                        "Chain {} failed to subscribe to logs: {}, retrying in {:?}",
+                        self.config.chain_id, e, backoff
+                    );
+                    self.reconnect_and_backoff(&mut backoff).await;
+                    continue;
+                }
+            };
+
+            info!(
+                "Chain {}: subscribed to logs via WebSocket from block {}",
+                self.config.chain_id, from_block
+            );
+            backoff = Duration::from_secs(1);
+
+            // `eth_subscribe("logs", filter)` only streams logs for blocks
+            // mined *after* the subscription takes effect - it does not
+            // replay history for the filter's `from_block`, regardless of
+            // what that's set to. Without this, every log emitted while we
+            // were disconnected (or during the gap between reading
+            // `from_block` and the subscription actually starting) would be
+            // silently and permanently dropped.
+            if let Err(e) = self.backfill_logs(contract_address, from_block).await {
+                warn!(
+                    "Chain {} backfill after (re)subscribe failed: {}",
+                    self.config.chain_id, e
+                );
+            }
+
+            let mut checkpoint_tick = tokio::time::interval(WS_POLL_INTERVAL);
+
+            loop {
+                tokio::select! {
+                    log = stream.next() => {
+                        match log {
+                            Some(log) => {
+                                let block_number = log.block_number.map(|b| b.as_u64());
+
+                                if let Err(e) = self.process_log(log).await {
+                                    error!("Failed to process log: {}", e);
+                                }
+
+                                if let Some(block_number) = block_number {
+                                    self.advance_checkpoint(block_number).await;
+                                }
+                            }
+                            None => {
+                                warn!(
+                                    "Chain {} WebSocket subscription ended, reconnecting",
+                                    self.config.chain_id
+                                );
+                                break;
+                            }
+                        }
+                    }
+
+                    _ = checkpoint_tick.tick() => {
+                        if let Ok(current_block) = self.provider.get_block_number().await {
+                            if let Err(e) = self.detect_and_rewind_reorg().await {
+                                warn!("Chain {} reorg check failed: {}", self.config.chain_id, e);
+                            }
+                            if let Ok(Some(block)) = self.provider.get_block(current_block).await {
+                                if let Some(hash) = block.hash {
+                                    self.record_block_hash(current_block, hash).await;
+                                }
+                            }
+                            self.advance_checkpoint(current_block).await;
+                        }
+                    }
+                }
+            }
+
+            self.reconnect_and_backoff(&mut backoff).await;
+        }
+    }
+
+    /// Reconnect the WebSocket provider and sleep for the current backoff,
+    /// doubling it for next time (capped at the polling interval)
+    async fn reconnect_and_backoff(&self, backoff: &mut Duration) {
+        if let Err(e) = self.provider.reconnect_ws().await {
+            warn!("Chain {} WebSocket reconnect failed: {}", self.config.chain_id, e);
+        }
+
+        tokio::time::sleep(*backoff).await;
+        *backoff = std::cmp::min(*backoff * 2, WS_POLL_INTERVAL);
+    }
+
+    /// Fetch and process any logs emitted in `[from_block, current_block]`
+    /// via `eth_getLogs`, the same catch-up `listen_polling` already relies
+    /// on for every scan, so a WebSocket gap (reconnect, dropped stream) is
+    /// closed before the fresh subscription's live logs start arriving.
+    async fn backfill_logs(&self, contract_address: Address, from_block: u64) -> RelayerResult<()> {
+        let current_block = self.provider.get_block_number().await?;
+        if current_block < from_block {
+            return Ok(());
+        }
+
         let filter = Filter::new()
             .address(contract_address)
-            .from_block(BlockNumber::Latest);
+            .from_block(from_block)
+            .to_block(current_block);
+
+        let logs = self.provider.get_logs(&filter).await?;
+        debug!(
+            "Chain {}: backfilled {} log(s) over blocks {}-{}",
+            self.config.chain_id,
+            logs.len(),
+            from_block,
+            current_block
+        );
 
-        // Note: In production, we'd use the WS provider's subscribe_logs
-        // For now, fall back to polling since ethers-rs WS can be tricky
-        self.listen_polling().await
+        for log in logs {
+            let block_number = log.block_number.map(|b| b.as_u64());
+            if let Err(e) = self.process_log(log).await {
+                error!("Failed to process backfilled log: {}", e);
+            }
+            if let Some(block_number) = block_number {
+                self.advance_checkpoint(block_number).await;
+            }
+        }
+
+        if let Ok(Some(block)) = self.provider.get_block(current_block).await {
+            if let Some(hash) = block.hash {
+                self.record_block_hash(current_block, hash).await;
+            }
+        }
+        self.advance_checkpoint(current_block).await;
+
+        Ok(())
+    }
+
+    /// Advance the scanned-block pointer if `block` is newer than what we
+    /// have, then persist the checkpoint up to the confirmed tip
+    async fn advance_checkpoint(&self, block: u64) {
+        {
+            let mut last_block = self.last_processed_block.write().await;
+            if block <= *last_block {
+                return;
+            }
+            *last_block = block;
+        }
+
+        crate::metrics::record_blocks_processed(self.config.chain_id, block);
+        self.persist_confirmed_checkpoint(block).await;
+    }
+
+    /// Persist the checkpoint up to `current_block - confirmation_blocks`,
+    /// never ahead of what's actually been scanned and never moving
+    /// backward except via an explicit reorg rewind in `detect_and_rewind_reorg`
+    async fn persist_confirmed_checkpoint(&self, current_block: u64) {
+        let scanned_to = *self.last_processed_block.read().await;
+        let confirmed = current_block.saturating_sub(self.provider.confirmation_blocks());
+        let target = std::cmp::min(scanned_to, confirmed);
+
+        let mut checkpointed = self.checkpointed_block.write().await;
+        if target <= *checkpointed {
+            return;
+        }
+        *checkpointed = target;
+
+        if let Err(e) = self.state_manager.save_checkpoint(self.config.chain_id, target).await {
+            warn!("Failed to save checkpoint: {}", e);
+        }
+    }
+
+    /// Record a processed block's hash, evicting the oldest entry once the
+    /// buffer exceeds `BLOCK_HASH_BUFFER_SIZE`
+    async fn record_block_hash(&self, block_number: u64, block_hash: H256) {
+        let mut buf = self.block_hashes.write().await;
+        if buf.back().map(|(n, _)| *n) == Some(block_number) {
+            return;
+        }
+        buf.push_back((block_number, block_hash));
+        while buf.len() > BLOCK_HASH_BUFFER_SIZE {
+            buf.pop_front();
+        }
+    }
+
+    /// Check whether the chain has reorged underneath our buffered blocks.
+    /// If so, walk backward through the buffer to find the common ancestor,
+    /// delete events stored above it, and rewind the checkpoint so the next
+    /// scan picks back up from there.
+    async fn detect_and_rewind_reorg(&self) -> RelayerResult<Option<u64>> {
+        let tip = { self.block_hashes.read().await.back().copied() };
+        let Some((tip_block, tip_hash)) = tip else {
+            return Ok(None);
+        };
+
+        let still_canonical = matches!(
+            self.provider.get_block(tip_block).await?,
+            Some(block) if block.hash == Some(tip_hash)
+        );
+        if still_canonical {
+            return Ok(None);
+        }
+
+        warn!(
+            "Chain {}: block {} ({:?}) no longer canonical, searching for common ancestor",
+            self.config.chain_id, tip_block, tip_hash
+        );
+
+        let candidates: Vec<(u64, H256)> = {
+            let buf = self.block_hashes.read().await;
+            buf.iter().rev().copied().collect()
+        };
+
+        let mut ancestor = None;
+        for (block_number, block_hash) in &candidates {
+            if let Ok(Some(block)) = self.provider.get_block(*block_number).await {
+                if block.hash == Some(*block_hash) {
+                    ancestor = Some(*block_number);
+                    break;
+                }
+            }
+        }
+
+        // Nothing in the buffer is canonical anymore - rewind past the whole
+        // buffered window and let the next scan re-derive from there.
+        let ancestor = ancestor.unwrap_or_else(|| {
+            candidates
+                .last()
+                .map(|(n, _)| n.saturating_sub(1))
+                .unwrap_or_else(|| tip_block.saturating_sub(1))
+        });
+
+        let reorg_depth = tip_block.saturating_sub(ancestor);
+        warn!(
+            "Chain {}: reorg detected (depth {}), common ancestor at block {}",
+            self.config.chain_id, reorg_depth, ancestor
+        );
+
+        // Delete events, revert affected transactions, and rewind the
+        // persisted checkpoint as a single transaction - see
+        // `StateManager::rollback_to_block` for why these can't be
+        // independent statements.
+        let (deleted, reverted) = self
+            .state_manager
+            .rollback_to_block(self.config.chain_id, ancestor)
+            .await?;
+        info!(
+            "Chain {}: removed {} event(s) above block {} after reorg",
+            self.config.chain_id, deleted, ancestor
+        );
+        if !reverted.is_empty() {
+            info!(
+                "Chain {}: reverted {} transaction(s) buffered above block {} back to Buffered after reorg",
+                self.config.chain_id, reverted.len(), ancestor
+            );
+        }
+
+        {
+            let mut buf = self.block_hashes.write().await;
+            buf.retain(|(n, _)| *n <= ancestor);
+        }
+        *self.last_processed_block.write().await = ancestor;
+        *self.checkpointed_block.write().await = ancestor;
+
+        crate::metrics::record_reorg(self.config.chain_id, reorg_depth);
+
+        // Let the coordination engine unwind any in-memory dependency-graph
+        // state (finalizations promoted off the now-retracted blocks)
+        // alongside the persisted rows already reverted above.
+        if self
+            .event_tx
+            .send(ContractEvent::ChainReorged {
+                chain_id: self.config.chain_id,
+                common_ancestor: ancestor,
+            })
+            .is_err()
+        {
+            // No receivers, that's okay
+        }
+
+        Ok(Some(ancestor))
     }
 
     /// HTTP polling-based event listening
@@ -112,6 +427,10 @@ impl ChainListener {
                 }
             };
 
+            if let Err(e) = self.detect_and_rewind_reorg().await {
+                warn!("Chain {} reorg check failed: {}", self.config.chain_id, e);
+            }
+
             let last_block = *self.last_processed_block.read().await;
 
             // Only process if we have new blocks
@@ -144,17 +463,13 @@ impl ChainListener {
                         }
                     }
 
-                    // Update checkpoint
-                    *self.last_processed_block.write().await = to_block;
-                    if let Err(e) = self
-                        .state_manager
-                        .save_checkpoint(self.config.chain_id, to_block)
-                        .await
-                    {
-                        warn!("Failed to save checkpoint: {}", e);
+                    if let Ok(Some(block)) = self.provider.get_block(to_block).await {
+                        if let Some(hash) = block.hash {
+                            self.record_block_hash(to_block, hash).await;
+                        }
                     }
 
-                    crate::metrics::record_blocks_processed(self.config.chain_id, to_block);
+                    self.advance_checkpoint(to_block).await;
                 }
                 Err(e) => {
                     warn!("Failed to get logs: {}", e);
@@ -166,8 +481,37 @@ impl ChainListener {
         }
     }
 
-    /// Process a single log entry
+    /// Process a single log entry, collapsing concurrent deliveries of the
+    /// same `(tx_hash, log_index)` (WS stream vs. catch-up poll) into one run
     async fn process_log(&self, log: Log) -> RelayerResult<()> {
+        let Some(tx_hash) = log.transaction_hash else {
+            // No tx hash to dedup on (shouldn't happen for a mined log) -
+            // just process it directly.
+            return self.process_log_inner(log).await;
+        };
+        let log_index = log.log_index.map(|i| i.as_u64()).unwrap_or(0);
+        let key = (self.config.chain_id, tx_hash, log_index);
+
+        self.process_map.dedup(key, || self.process_log_inner(log)).await
+    }
+
+    /// The actual log-processing work, run at most once per
+    /// `(chain_id, tx_hash, log_index)` via `process_log`'s dedup. That
+    /// dedup only collapses *concurrent* deliveries though (e.g. a live WS
+    /// log racing a backfill poll over the same block range) - a delivery
+    /// that arrives after an earlier run already finished and cleared the
+    /// in-flight entry (a backfill re-scanning a range the live stream
+    /// already covered, or a restart replaying the same blocks) needs
+    /// `store_event`'s `(chain_id, tx_hash, log_index)` uniqueness to catch
+    /// it instead, so this only broadcasts - and only feeds downstream
+    /// handlers like `DependencyGraph::add_transaction` - the first time a
+    /// log is actually stored.
+    async fn process_log_inner(&self, log: Log) -> RelayerResult<()> {
+        if let (Some(block_number), Some(block_hash)) = (log.block_number, log.block_hash) {
+            self.record_block_hash(block_number.as_u64(), block_hash).await;
+        }
+
+        let log_index = log.log_index.map(|i| i.as_u64()).unwrap_or(0);
         let event = self.event_parser.parse_log(&log)?;
 
         debug!(
@@ -175,6 +519,16 @@ impl ChainListener {
             self.config.chain_id, event
         );
 
+        // Store event in database (idempotent on (chain_id, tx_hash, log_index))
+        let newly_stored = self.state_manager.store_event(&event, log_index).await?;
+        if !newly_stored {
+            debug!(
+                "Chain {} event already stored, skipping re-broadcast: {:?}",
+                self.config.chain_id, event
+            );
+            return Ok(());
+        }
+
         // Record metric
         crate::metrics::record_event(self.config.chain_id, &event);
 
@@ -183,9 +537,6 @@ impl ChainListener {
             // No receivers, that's okay
         }
 
-        // Store event in database
-        self.state_manager.store_event(&event).await?;
-
         Ok(())
     }
 }
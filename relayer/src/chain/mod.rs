@@ -6,15 +6,17 @@
 //! - Chain-specific finality tracking
 //! - Automatic reconnection and health monitoring
 
+pub mod dedup;
 pub mod finality;
 pub mod listener;
 pub mod provider;
 
-pub use finality::FinalityTracker;
+pub use dedup::ProcessMap;
+pub use finality::{FinalityTracker, L1AnchorConfig};
 pub use listener::ChainListener;
-pub use provider::{ChainProvider, GasPrice};
+pub use provider::{ChainProvider, EndpointStatus, GasPrice};
 
-use crate::config::{ChainConfig, Settings};
+use crate::config::{ChainConfig, FinalityBackendKind, Settings};
 use crate::error::{RelayerError, RelayerResult};
 use crate::events::ContractEvent;
 use crate::state::StateManager;
@@ -38,6 +40,9 @@ pub struct ChainManager {
     state_manager: Arc<StateManager>,
     /// Shutdown signal
     shutdown: Arc<RwLock<bool>>,
+    /// Running listener task per chain, so `reconcile` can tear one down
+    /// individually when its chain is disabled or removed from config
+    listener_handles: DashMap<u64, tokio::task::JoinHandle<()>>,
 }
 
 impl ChainManager {
@@ -48,7 +53,10 @@ impl ChainManager {
         let listeners = DashMap::new();
         let finality_trackers = DashMap::new();
 
-        // Initialize providers for all enabled chains
+        // Pass 1: providers and listeners for all enabled chains. Providers
+        // go in first (and fully) so that pass 2 can wire an L1-anchored
+        // finality backend to its parent chain's provider regardless of
+        // which chain is configured first.
         for (name, chain_config) in settings.enabled_chains() {
             if chain_config.contract_address.is_empty() {
                 warn!("Skipping chain {} - no contract address configured", name);
@@ -60,20 +68,9 @@ impl ChainManager {
                 chain_config.name, chain_config.chain_id
             );
 
-            // Create provider
-            let provider = ChainProvider::new(chain_config.clone()).await?;
-            let provider = Arc::new(provider);
+            let provider = Arc::new(ChainProvider::new(chain_config.clone()).await?);
             providers.insert(chain_config.chain_id, provider.clone());
 
-            // Create finality tracker
-            let finality = FinalityTracker::new(
-                chain_config.chain_id,
-                chain_config.confirmation_blocks,
-                provider.clone(),
-            );
-            finality_trackers.insert(chain_config.chain_id, Arc::new(finality));
-
-            // Create listener
             let listener = ChainListener::new(
                 chain_config.clone(),
                 provider.clone(),
@@ -82,6 +79,34 @@ impl ChainManager {
             )
             .await?;
             listeners.insert(chain_config.chain_id, Arc::new(listener));
+        }
+
+        // Pass 2: finality trackers, resuming any anchors a prior crash left
+        // persisted rather than silently dropping them
+        for (name, chain_config) in settings.enabled_chains() {
+            let Some(provider) = providers.get(&chain_config.chain_id).map(|p| p.clone()) else {
+                continue; // skipped above for lacking a contract address
+            };
+
+            let l1_anchor = match chain_config.finality_backend {
+                FinalityBackendKind::L1Anchored => Some(Self::resolve_l1_anchor(
+                    name,
+                    chain_config,
+                    &providers,
+                )?),
+                _ => None,
+            };
+
+            let finality = FinalityTracker::restore(
+                chain_config.chain_id,
+                chain_config.confirmation_blocks,
+                chain_config.finality_backend.clone(),
+                provider,
+                state_manager.clone(),
+                l1_anchor,
+            )
+            .await?;
+            finality_trackers.insert(chain_config.chain_id, Arc::new(finality));
 
             info!("Chain {} initialized successfully", chain_config.name);
         }
@@ -93,39 +118,96 @@ impl ChainManager {
             event_tx,
             state_manager,
             shutdown: Arc::new(RwLock::new(false)),
+            listener_handles: DashMap::new(),
         })
     }
 
+    /// Build the `L1AnchorConfig` a rollup's `L1Anchored` finality backend
+    /// needs, looking up its parent chain's already-constructed provider
+    fn resolve_l1_anchor(
+        name: &str,
+        chain_config: &ChainConfig,
+        providers: &DashMap<u64, Arc<ChainProvider>>,
+    ) -> RelayerResult<L1AnchorConfig> {
+        let l1_chain_id = chain_config.l1_chain_id.ok_or_else(|| {
+            RelayerError::Config(format!(
+                "chain {} uses the l1_anchored finality backend but has no l1_chain_id configured",
+                name
+            ))
+        })?;
+        let batch_contract_address = chain_config.l1_batch_contract_address.clone().ok_or_else(|| {
+            RelayerError::Config(format!(
+                "chain {} uses the l1_anchored finality backend but has no l1_batch_contract_address configured",
+                name
+            ))
+        })?;
+        let confirmed_block_selector =
+            chain_config.l1_confirmed_block_selector.clone().ok_or_else(|| {
+                RelayerError::Config(format!(
+                    "chain {} uses the l1_anchored finality backend but has no l1_confirmed_block_selector configured",
+                    name
+                ))
+            })?;
+        let l1_provider = providers.get(&l1_chain_id).map(|p| p.clone()).ok_or_else(|| {
+            RelayerError::Config(format!(
+                "chain {} anchors to L1 chain {} but that chain isn't configured/enabled",
+                name, l1_chain_id
+            ))
+        })?;
+
+        Ok(L1AnchorConfig { l1_provider, batch_contract_address, confirmed_block_selector })
+    }
+
     /// Start all chain listeners
     pub async fn start_listeners(&self) -> RelayerResult<()> {
-        let mut handles = Vec::new();
-
-        for entry in self.listeners.iter() {
-            let listener = entry.value().clone();
-            let shutdown = self.shutdown.clone();
-
-            let handle = tokio::spawn(async move {
-                loop {
-                    if *shutdown.read().await {
-                        break;
-                    }
-
-                    if let Err(e) = listener.listen().await {
-                        error!("Listener error for chain {}: {}", listener.chain_id(), e);
-                        // Reconnect after delay
-                        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                    }
-                }
-            });
-
-            handles.push(handle);
+        let chain_ids: Vec<u64> = self.listeners.iter().map(|e| *e.key()).collect();
+        for chain_id in chain_ids {
+            self.spawn_listener(chain_id);
         }
 
-        // Wait for all listeners
+        // Wait for all listeners to exit (only happens on shutdown, since
+        // each loops forever otherwise)
+        loop {
+            if *self.shutdown.read().await {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        }
+        let handles: Vec<_> = self
+            .listener_handles
+            .iter()
+            .map(|e| *e.key())
+            .filter_map(|chain_id| self.listener_handles.remove(&chain_id).map(|(_, h)| h))
+            .collect();
         futures::future::join_all(handles).await;
         Ok(())
     }
 
+    /// Spawn the listen loop for a single chain and track its handle, so it
+    /// can later be torn down individually by `reconcile`
+    fn spawn_listener(&self, chain_id: u64) {
+        let Some(listener) = self.listeners.get(&chain_id).map(|l| l.clone()) else {
+            return;
+        };
+        let shutdown = self.shutdown.clone();
+
+        let handle = tokio::spawn(async move {
+            loop {
+                if *shutdown.read().await {
+                    break;
+                }
+
+                if let Err(e) = listener.listen().await {
+                    error!("Listener error for chain {}: {}", listener.chain_id(), e);
+                    // Reconnect after delay
+                    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+                }
+            }
+        });
+
+        self.listener_handles.insert(chain_id, handle);
+    }
+
     /// Subscribe to contract events from all chains
     pub fn subscribe_events(&self) -> broadcast::Receiver<ContractEvent> {
         self.event_tx.subscribe()
@@ -173,4 +255,116 @@ impl ChainManager {
         *self.shutdown.write().await = true;
         info!("Chain manager stopped");
     }
+
+    /// Reconcile running state against a freshly re-loaded `Settings`,
+    /// applied on a SIGHUP config reload. Chains present both before and
+    /// after are reconciled in place via `ChainProvider::reconcile` (picking
+    /// up new `rpc_urls`/`ws_url`/gas settings without a restart); newly
+    /// enabled chains are spun up and their listener started; chains no
+    /// longer enabled have their listener stopped and are dropped.
+    ///
+    /// A chain whose reconciliation or startup fails is logged and left out
+    /// rather than aborting the whole reload, so one bad chain config
+    /// doesn't take down every other chain's reload.
+    pub async fn reconcile(&self, settings: &Settings) {
+        let new_chain_ids: std::collections::HashSet<u64> = settings
+            .enabled_chains()
+            .into_iter()
+            .filter(|(_, c)| !c.contract_address.is_empty())
+            .map(|(_, c)| c.chain_id)
+            .collect();
+
+        // Chains removed or disabled: stop their listener and drop state
+        let removed: Vec<u64> = self
+            .providers
+            .iter()
+            .map(|e| *e.key())
+            .filter(|id| !new_chain_ids.contains(id))
+            .collect();
+        for chain_id in removed {
+            if let Some((_, handle)) = self.listener_handles.remove(&chain_id) {
+                handle.abort();
+            }
+            self.listeners.remove(&chain_id);
+            self.finality_trackers.remove(&chain_id);
+            self.providers.remove(&chain_id);
+            info!("Chain {} disabled on reload - listener stopped", chain_id);
+        }
+
+        // Chains present before and after: hot-reconcile the provider
+        for (name, chain_config) in settings.enabled_chains() {
+            if chain_config.contract_address.is_empty() {
+                continue;
+            }
+            if let Some(provider) = self.providers.get(&chain_config.chain_id).map(|p| p.clone()) {
+                if let Err(e) = provider.reconcile(chain_config.clone()).await {
+                    error!(
+                        "Failed to reconcile chain {} ({}) on reload: {}",
+                        chain_config.chain_id, name, e
+                    );
+                }
+            }
+        }
+
+        // Newly enabled chains: construct and start them fresh
+        for (name, chain_config) in settings.enabled_chains() {
+            if chain_config.contract_address.is_empty() || self.providers.contains_key(&chain_config.chain_id) {
+                continue;
+            }
+
+            info!("Chain {} ({}) enabled on reload - starting up", name, chain_config.chain_id);
+            let provider = match ChainProvider::new(chain_config.clone()).await {
+                Ok(p) => Arc::new(p),
+                Err(e) => {
+                    error!("Failed to start chain {} on reload: {}", chain_config.chain_id, e);
+                    continue;
+                }
+            };
+            self.providers.insert(chain_config.chain_id, provider.clone());
+
+            let listener = match ChainListener::new(
+                chain_config.clone(),
+                provider.clone(),
+                self.event_tx.clone(),
+                self.state_manager.clone(),
+            )
+            .await
+            {
+                Ok(l) => Arc::new(l),
+                Err(e) => {
+                    error!("Failed to start listener for chain {} on reload: {}", chain_config.chain_id, e);
+                    self.providers.remove(&chain_config.chain_id);
+                    continue;
+                }
+            };
+            self.listeners.insert(chain_config.chain_id, listener);
+            self.spawn_listener(chain_config.chain_id);
+
+            if chain_config.finality_backend == FinalityBackendKind::L1Anchored {
+                warn!(
+                    "Chain {} uses the l1_anchored finality backend, which is not reconstructed on a live reload - restart the relayer to pick it up",
+                    chain_config.chain_id
+                );
+                continue;
+            }
+
+            match FinalityTracker::restore(
+                chain_config.chain_id,
+                chain_config.confirmation_blocks,
+                chain_config.finality_backend.clone(),
+                provider,
+                self.state_manager.clone(),
+                None,
+            )
+            .await
+            {
+                Ok(finality) => {
+                    self.finality_trackers.insert(chain_config.chain_id, Arc::new(finality));
+                }
+                Err(e) => {
+                    error!("Failed to start finality tracker for chain {} on reload: {}", chain_config.chain_id, e);
+                }
+            }
+        }
+    }
 }
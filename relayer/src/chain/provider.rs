@@ -4,57 +4,148 @@ use crate::config::{ChainConfig, GasPriceStrategy};
 use crate::error::{RelayerError, RelayerResult};
 
 use ethers::prelude::*;
-use ethers::providers::{Http, Provider, Ws};
+use ethers::providers::{Http, Provider, ProviderError, Ws};
 use ethers::types::transaction::eip2718::TypedTransaction;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use ethers::types::transaction::eip2930::AccessList;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock as SyncRwLock};
 use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
-/// Multi-provider wrapper with automatic failover
-pub struct ChainProvider {
-    /// Chain configuration
-    config: ChainConfig,
-    /// HTTP providers (multiple for failover)
-    http_providers: Vec<Provider<Http>>,
-    /// Current active provider index
-    current_provider: AtomicUsize,
-    /// WebSocket provider (optional, for event streaming)
-    ws_provider: RwLock<Option<Provider<Ws>>>,
-    /// Last known block number
-    last_block: RwLock<u64>,
+/// Retries against a single endpoint before rotating to the next one
+const MAX_RETRIES_PER_ENDPOINT: u32 = 3;
+/// Base backoff between retries on the same endpoint (scaled by attempt number)
+const RETRY_BACKOFF: Duration = Duration::from_millis(200);
+/// Consecutive failures before an endpoint is considered unhealthy
+const UNHEALTHY_FAILURE_THRESHOLD: u32 = 3;
+
+/// Optimism's predeployed `GasPriceOracle`, at the same address on every OP-stack chain
+const OPTIMISM_GAS_PRICE_ORACLE: &str = "0x420000000000000000000000000000000000000F";
+/// `GasPriceOracle.l1BaseFee() returns (uint256)`
+const OPTIMISM_L1_BASE_FEE_SELECTOR: [u8; 4] = [0x51, 0x9b, 0x4b, 0xd3];
+/// `GasPriceOracle.overhead() returns (uint256)`
+const OPTIMISM_OVERHEAD_SELECTOR: [u8; 4] = [0x0c, 0x18, 0xc1, 0x62];
+/// `GasPriceOracle.scalar() returns (uint256)`
+const OPTIMISM_SCALAR_SELECTOR: [u8; 4] = [0xf4, 0x5e, 0x65, 0xd8];
+
+/// Arbitrum's `ArbGasInfo` precompile, at the same address on every Arbitrum chain
+const ARBITRUM_GAS_INFO: &str = "0x000000000000000000000000000000000000006C";
+/// `ArbGasInfo.getL1BaseFeeEstimate() returns (uint256)` - the current L1
+/// calldata price, in wei per byte
+const ARBITRUM_L1_BASE_FEE_ESTIMATE_SELECTOR: [u8; 4] = [0xf5, 0xd6, 0xde, 0xd7];
+
+/// Tracks per-endpoint health so failover can skip dead or lagging nodes
+struct EndpointHealth {
+    url: String,
+    consecutive_failures: AtomicU32,
+    last_seen_block: AtomicU64,
 }
 
-impl ChainProvider {
-    /// Create a new chain provider
-    pub async fn new(config: ChainConfig) -> RelayerResult<Self> {
-        let mut http_providers = Vec::new();
+impl EndpointHealth {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            consecutive_failures: AtomicU32::new(0),
+            last_seen_block: AtomicU64::new(0),
+        }
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    fn record_failure(&self) {
+        self.consecutive_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_block_seen(&self, block: u64) {
+        self.last_seen_block.fetch_max(block, Ordering::Relaxed);
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.consecutive_failures.load(Ordering::Relaxed) < UNHEALTHY_FAILURE_THRESHOLD
+    }
+
+    fn last_seen_block(&self) -> u64 {
+        self.last_seen_block.load(Ordering::Relaxed)
+    }
+
+    fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-endpoint health snapshot, exposed through the `/status` API
+#[derive(Debug, Clone, Serialize)]
+pub struct EndpointStatus {
+    pub url: String,
+    pub healthy: bool,
+    pub consecutive_failures: u32,
+    pub last_seen_block: u64,
+    pub active: bool,
+}
+
+/// The set of HTTP providers and their health, rebuilt wholesale by
+/// `ChainProvider::reconcile` when `rpc_urls` changes
+struct ProviderSet {
+    entries: Vec<(Provider<Http>, Arc<EndpointHealth>)>,
+}
+
+impl ProviderSet {
+    /// Build a provider set from `config.rpc_urls`, skipping any URL that
+    /// fails to parse. Errors only if none of them did.
+    fn build(config: &ChainConfig) -> RelayerResult<Self> {
+        let mut entries = Vec::new();
 
-        // Initialize HTTP providers
         for url in &config.rpc_urls {
             match Provider::<Http>::try_from(url.as_str()) {
                 Ok(provider) => {
                     let provider = provider.interval(Duration::from_millis(100));
-                    http_providers.push(provider);
+                    entries.push((provider, Arc::new(EndpointHealth::new(url.clone()))));
                     debug!("Added HTTP provider for chain {}: {}", config.chain_id, url);
                 }
                 Err(e) => {
-                    warn!(
-                        "Failed to create provider for {}: {}",
-                        url, e
-                    );
+                    warn!("Failed to create provider for {}: {}", url, e);
                 }
             }
         }
 
-        if http_providers.is_empty() {
+        if entries.is_empty() {
             return Err(RelayerError::ChainConnection {
                 chain_id: config.chain_id,
                 message: "No valid RPC providers".to_string(),
             });
         }
 
+        Ok(Self { entries })
+    }
+}
+
+/// Multi-provider wrapper with automatic failover
+pub struct ChainProvider {
+    /// Chain configuration. Behind a lock (rather than a plain field) so
+    /// `reconcile` can hot-swap it on a config reload without restarting
+    /// the relayer.
+    config: SyncRwLock<ChainConfig>,
+    /// HTTP providers and their health (multiple for failover), rebuilt by
+    /// `reconcile` when `rpc_urls` changes
+    providers: SyncRwLock<ProviderSet>,
+    /// Current active provider index
+    current_provider: AtomicUsize,
+    /// WebSocket provider (optional, for event streaming)
+    ws_provider: RwLock<Option<Provider<Ws>>>,
+    /// Last known block number
+    last_block: RwLock<u64>,
+}
+
+impl ChainProvider {
+    /// Create a new chain provider
+    pub async fn new(config: ChainConfig) -> RelayerResult<Self> {
+        let providers = ProviderSet::build(&config)?;
+
         // Try to initialize WebSocket provider
         let ws_provider = if let Some(ref ws_url) = config.ws_url {
             match Provider::<Ws>::connect(ws_url).await {
@@ -75,15 +166,16 @@ impl ChainProvider {
         };
 
         // Get initial block number
-        let initial_block = http_providers[0]
+        let initial_block = providers.entries[0]
+            .0
             .get_block_number()
             .await
             .map(|b| b.as_u64())
             .unwrap_or(0);
 
         Ok(Self {
-            config,
-            http_providers,
+            config: SyncRwLock::new(config),
+            providers: SyncRwLock::new(providers),
             current_provider: AtomicUsize::new(0),
             ws_provider: RwLock::new(ws_provider),
             last_block: RwLock::new(initial_block),
@@ -91,9 +183,10 @@ impl ChainProvider {
     }
 
     /// Get the active HTTP provider
-    pub fn http(&self) -> &Provider<Http> {
-        let idx = self.current_provider.load(Ordering::Relaxed);
-        &self.http_providers[idx % self.http_providers.len()]
+    pub fn http(&self) -> Provider<Http> {
+        let providers = self.providers.read().unwrap();
+        let idx = self.current_provider.load(Ordering::Relaxed) % providers.entries.len();
+        providers.entries[idx].0.clone()
     }
 
     /// Get WebSocket provider if available
@@ -103,49 +196,142 @@ impl ChainProvider {
 
     /// Switch to next available provider
     pub fn failover(&self) {
+        let len = self.providers.read().unwrap().entries.len();
         let current = self.current_provider.load(Ordering::Relaxed);
-        let next = (current + 1) % self.http_providers.len();
+        let next = (current + 1) % len;
         self.current_provider.store(next, Ordering::Relaxed);
         warn!(
             "Chain {} failover to provider {}",
-            self.config.chain_id, next
+            self.config.read().unwrap().chain_id,
+            next
         );
     }
 
-    /// Get current block number with failover
-    pub async fn get_block_number(&self) -> RelayerResult<u64> {
-        for _ in 0..self.http_providers.len() {
-            match self.http().get_block_number().await {
-                Ok(block) => {
-                    let block_num = block.as_u64();
-                    *self.last_block.write().await = block_num;
-                    return Ok(block_num);
-                }
-                Err(e) => {
-                    warn!(
-                        "Failed to get block number from chain {}: {}",
-                        self.config.chain_id, e
-                    );
-                    self.failover();
+    /// Point the active provider at the first healthy endpoint whose last
+    /// seen block is at or above `min_block`, leaving it unchanged if none
+    /// qualifies (a stale-but-healthy endpoint is still better than nothing)
+    fn prefer_caught_up_endpoint(&self, min_block: u64) {
+        let providers = self.providers.read().unwrap();
+        if let Some(idx) = providers
+            .entries
+            .iter()
+            .position(|(_, health)| health.is_healthy() && health.last_seen_block() >= min_block)
+        {
+            self.current_provider.store(idx, Ordering::Relaxed);
+        }
+    }
+
+    /// Call `f` against the active endpoint, retrying with backoff up to
+    /// `MAX_RETRIES_PER_ENDPOINT` times before rotating to the next one.
+    /// Tries every configured endpoint once before giving up.
+    async fn call_with_failover<T, F, Fut>(&self, mut f: F) -> RelayerResult<T>
+    where
+        F: FnMut(&Provider<Http>, &EndpointHealth) -> Fut,
+        Fut: Future<Output = Result<T, ProviderError>>,
+    {
+        let len = self.providers.read().unwrap().entries.len();
+
+        for _ in 0..len {
+            let idx = self.current_provider.load(Ordering::Relaxed) % len;
+            let (provider, health) = self.providers.read().unwrap().entries[idx].clone();
+
+            let mut last_error = None;
+            for attempt in 0..MAX_RETRIES_PER_ENDPOINT {
+                match f(&provider, &health).await {
+                    Ok(result) => {
+                        health.record_success();
+                        return Ok(result);
+                    }
+                    Err(e) => {
+                        last_error = Some(e);
+                        if attempt + 1 < MAX_RETRIES_PER_ENDPOINT {
+                            tokio::time::sleep(RETRY_BACKOFF * (attempt + 1)).await;
+                        }
+                    }
                 }
             }
+
+            warn!(
+                "Chain {} endpoint {} failed after {} attempts: {}",
+                self.config.read().unwrap().chain_id,
+                health.url,
+                MAX_RETRIES_PER_ENDPOINT,
+                last_error.unwrap()
+            );
+            health.record_failure();
+            self.failover();
         }
 
         Err(RelayerError::ChainConnection {
-            chain_id: self.config.chain_id,
-            message: "All providers failed".to_string(),
+            chain_id: self.config.read().unwrap().chain_id,
+            message: "All RPC endpoints failed".to_string(),
         })
     }
 
+    /// Get current block number with failover
+    pub async fn get_block_number(&self) -> RelayerResult<u64> {
+        let block = self
+            .call_with_failover(|provider, health| async move {
+                let block = provider.get_block_number().await?;
+                health.record_block_seen(block.as_u64());
+                Ok(block)
+            })
+            .await?;
+
+        let block_num = block.as_u64();
+        *self.last_block.write().await = block_num;
+        Ok(block_num)
+    }
+
+    /// Resolve a symbolic block tag (e.g. `BlockNumber::Finalized`,
+    /// `BlockNumber::Safe`) to a concrete block number
+    pub async fn get_block_number_by_tag(&self, tag: BlockNumber) -> RelayerResult<u64> {
+        let block = self
+            .call_with_failover(|provider, _health| provider.get_block(tag))
+            .await?
+            .ok_or_else(|| RelayerError::ChainConnection {
+                chain_id: self.config.read().unwrap().chain_id,
+                message: format!("node returned no block for tag {:?}", tag),
+            })?;
+
+        block.number.map(|n| n.as_u64()).ok_or_else(|| RelayerError::ChainConnection {
+            chain_id: self.config.read().unwrap().chain_id,
+            message: format!("block for tag {:?} has no number yet", tag),
+        })
+    }
+
+    /// Perform a read-only `eth_call` against `to` as of `at`, returning the
+    /// raw response bytes. Used by finality backends that need to query an
+    /// arbitrary contract (e.g. a rollup's batch-posting contract) without a
+    /// full ABI/Contract binding.
+    pub async fn call_raw(&self, to: Address, data: Vec<u8>, at: BlockNumber) -> RelayerResult<Bytes> {
+        let tx: TypedTransaction = TransactionRequest::new().to(to).data(data).into();
+        self.call_with_failover(|provider, _health| provider.call(&tx, Some(at.into())))
+            .await
+    }
+
+    /// Snapshot of per-endpoint health, for the `/status` API
+    pub fn endpoint_status(&self) -> Vec<EndpointStatus> {
+        let providers = self.providers.read().unwrap();
+        let active = self.current_provider.load(Ordering::Relaxed) % providers.entries.len();
+        providers
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, health))| EndpointStatus {
+                url: health.url.clone(),
+                healthy: health.is_healthy(),
+                consecutive_failures: health.consecutive_failures(),
+                last_seen_block: health.last_seen_block(),
+                active: idx == active,
+            })
+            .collect()
+    }
+
     /// Get block with transaction receipts
     pub async fn get_block(&self, block_number: u64) -> RelayerResult<Option<Block<H256>>> {
-        self.http()
-            .get_block(block_number)
+        self.call_with_failover(|provider, _health| provider.get_block(block_number))
             .await
-            .map_err(|e| RelayerError::ChainConnection {
-                chain_id: self.config.chain_id,
-                message: e.to_string(),
-            })
     }
 
     /// Get transaction receipt
@@ -153,34 +339,20 @@ impl ChainProvider {
         &self,
         tx_hash: H256,
     ) -> RelayerResult<Option<TransactionReceipt>> {
-        self.http()
-            .get_transaction_receipt(tx_hash)
+        self.call_with_failover(|provider, _health| provider.get_transaction_receipt(tx_hash))
             .await
-            .map_err(|e| RelayerError::ChainConnection {
-                chain_id: self.config.chain_id,
-                message: e.to_string(),
-            })
     }
 
-    /// Get logs for a filter
+    /// Get logs for a filter. If the filter has a concrete `to_block`, the
+    /// active endpoint is switched first to one whose head has reached it,
+    /// so a lagging node never silently returns a truncated log range.
     pub async fn get_logs(&self, filter: &Filter) -> RelayerResult<Vec<Log>> {
-        for _ in 0..self.http_providers.len() {
-            match self.http().get_logs(filter).await {
-                Ok(logs) => return Ok(logs),
-                Err(e) => {
-                    warn!(
-                        "Failed to get logs from chain {}: {}",
-                        self.config.chain_id, e
-                    );
-                    self.failover();
-                }
-            }
+        if let Some(BlockNumber::Number(to_block)) = filter.get_to_block() {
+            self.prefer_caught_up_endpoint(to_block.as_u64());
         }
 
-        Err(RelayerError::ChainConnection {
-            chain_id: self.config.chain_id,
-            message: "All providers failed to get logs".to_string(),
-        })
+        self.call_with_failover(|provider, _health| provider.get_logs(filter))
+            .await
     }
 
     /// Estimate gas for a transaction
@@ -191,9 +363,24 @@ impl ChainProvider {
             .map_err(|e| RelayerError::GasEstimation(e.to_string()))
     }
 
+    /// Request an EIP-2930 access list for a transaction via `eth_createAccessList`
+    pub async fn create_access_list(
+        &self,
+        tx: &TypedTransaction,
+    ) -> RelayerResult<(AccessList, U256)> {
+        let result = self
+            .http()
+            .create_access_list(tx, None)
+            .await
+            .map_err(|e| RelayerError::GasEstimation(format!("eth_createAccessList failed: {}", e)))?;
+
+        Ok((result.access_list, result.gas_used))
+    }
+
     /// Get current gas price based on chain strategy
     pub async fn get_gas_price(&self) -> RelayerResult<GasPrice> {
-        match self.config.gas_price_strategy {
+        let strategy = self.config.read().unwrap().gas_price_strategy.clone();
+        match strategy {
             GasPriceStrategy::Legacy => {
                 let price = self.http().get_gas_price().await.map_err(|e| {
                     RelayerError::GasEstimation(e.to_string())
@@ -217,8 +404,84 @@ impl ChainProvider {
         }
     }
 
-    /// Estimate EIP-1559 fees
-    async fn estimate_eip1559_fees(&self) -> RelayerResult<(U256, U256)> {
+    /// L1 data-availability surcharge for posting `calldata` on this L2, in
+    /// wei. Zero on chains that don't separate L1 and L2 costs - on
+    /// Optimism and Arbitrum this is the dominant component of total cost
+    /// and `GasEstimator::calculate_cost` folds it in alongside L2 execution.
+    pub async fn l1_data_fee(&self, calldata: &[u8]) -> RelayerResult<U256> {
+        let strategy = self.config.read().unwrap().gas_price_strategy.clone();
+        match strategy {
+            GasPriceStrategy::Optimism => self.optimism_l1_data_fee(calldata).await,
+            GasPriceStrategy::Arbitrum => self.arbitrum_l1_data_fee(calldata).await,
+            GasPriceStrategy::Legacy | GasPriceStrategy::Eip1559 => Ok(U256::zero()),
+        }
+    }
+
+    /// OP-stack's formula: `(zero_bytes * 4 + nonzero_bytes * 16 + overhead)
+    /// * l1BaseFee * scalar / 1e6`, read live from the `GasPriceOracle` predeploy
+    async fn optimism_l1_data_fee(&self, calldata: &[u8]) -> RelayerResult<U256> {
+        let oracle: Address = OPTIMISM_GAS_PRICE_ORACLE
+            .parse()
+            .expect("hardcoded predeploy address is valid");
+
+        let l1_base_fee = self
+            .read_oracle_u256(oracle, OPTIMISM_L1_BASE_FEE_SELECTOR)
+            .await?;
+        let overhead = self.read_oracle_u256(oracle, OPTIMISM_OVERHEAD_SELECTOR).await?;
+        let scalar = self.read_oracle_u256(oracle, OPTIMISM_SCALAR_SELECTOR).await?;
+
+        let (zero_bytes, nonzero_bytes) = count_calldata_bytes(calldata);
+        let calldata_gas = U256::from(zero_bytes) * 4 + U256::from(nonzero_bytes) * 16;
+
+        Ok((calldata_gas + overhead) * l1_base_fee * scalar / U256::from(1_000_000u64))
+    }
+
+    /// Arbitrum's `ArbGasInfo` precompile reports the L1 calldata price
+    /// directly in wei per byte, already folding in Arbitrum's own
+    /// compression accounting
+    async fn arbitrum_l1_data_fee(&self, calldata: &[u8]) -> RelayerResult<U256> {
+        let precompile: Address = ARBITRUM_GAS_INFO
+            .parse()
+            .expect("hardcoded precompile address is valid");
+
+        let l1_price_per_byte = self
+            .read_oracle_u256(precompile, ARBITRUM_L1_BASE_FEE_ESTIMATE_SELECTOR)
+            .await?;
+
+        Ok(U256::from(calldata.len() as u64) * l1_price_per_byte)
+    }
+
+    /// Read a zero-argument `uint256` view function as of the latest block
+    async fn read_oracle_u256(&self, to: Address, selector: [u8; 4]) -> RelayerResult<U256> {
+        let response = self.call_raw(to, selector.to_vec(), BlockNumber::Latest).await?;
+        Ok(U256::from_big_endian(&response))
+    }
+
+    /// Estimate EIP-1559 fees.
+    ///
+    /// Prefers `eth_feeHistory` over the last `fee_history_lookback_blocks`
+    /// blocks: the priority fee is the average of the
+    /// `fee_history_reward_percentile` reward across blocks that returned a
+    /// nonzero one, and the next block's base fee is predicted by applying
+    /// the EIP-1559 update rule to the latest block's base fee and gas
+    /// usage, rather than trusting a single point-in-time value. Falls back
+    /// to the latest block's base fee plus a flat 2 gwei priority fee when
+    /// the node doesn't support `eth_feeHistory`.
+    ///
+    /// `pub(crate)` so `GasEstimator::get_gas_price` can call this directly
+    /// as the single EIP-1559 fee estimate rather than maintaining a second,
+    /// divergent `eth_feeHistory` implementation of its own.
+    pub(crate) async fn estimate_eip1559_fees(&self) -> RelayerResult<(U256, U256)> {
+        match self.fee_history_eip1559_fees().await {
+            Ok(fees) => return Ok(fees),
+            Err(e) => {
+                warn!(
+                    "eth_feeHistory unavailable for chain {}: {}, falling back to latest-block heuristic",
+                    self.config.read().unwrap().chain_id, e
+                );
+            }
+        }
+
         let block = self
             .http()
             .get_block(BlockNumber::Latest)
@@ -230,17 +493,64 @@ impl ChainProvider {
             .base_fee_per_gas
             .ok_or_else(|| RelayerError::GasEstimation("No base fee in block".to_string()))?;
 
-        // Priority fee estimation (can be improved with fee history)
         let priority_fee = U256::from(2_000_000_000u64); // 2 gwei default
-
-        // Max fee = 2 * base_fee + priority_fee (buffer for block variability)
         let max_fee = base_fee * 2 + priority_fee;
+        Ok((self.cap_max_fee(max_fee), priority_fee))
+    }
 
-        // Cap at configured max
-        let max_gwei = U256::from(self.config.max_gas_price_gwei) * U256::from(1_000_000_000u64);
-        let max_fee = std::cmp::min(max_fee, max_gwei);
+    /// `estimate_eip1559_fees`'s `eth_feeHistory`-driven path; split out so
+    /// the caller can fall back cleanly when the node doesn't support it.
+    async fn fee_history_eip1559_fees(&self) -> RelayerResult<(U256, U256)> {
+        let (lookback, percentile) = {
+            let config = self.config.read().unwrap();
+            (config.fee_history_lookback_blocks, config.fee_history_reward_percentile)
+        };
+
+        let history = self
+            .http()
+            .fee_history(lookback, BlockNumber::Latest, &[percentile])
+            .await
+            .map_err(|e| RelayerError::GasEstimation(format!("eth_feeHistory failed: {}", e)))?;
+
+        let rewards: Vec<U256> = history
+            .reward
+            .iter()
+            .filter_map(|row| row.first().copied())
+            .filter(|reward| !reward.is_zero())
+            .collect();
+
+        let priority_fee = if rewards.is_empty() {
+            U256::zero()
+        } else {
+            rewards.iter().fold(U256::zero(), |sum, r| sum + r) / U256::from(rewards.len())
+        };
+
+        // `base_fee_per_gas` has one more entry than `gas_used_ratio` (the
+        // node appends its own predicted next-block base fee); use the
+        // latest *observed* block's base fee and usage ratio so the update
+        // rule below computes the prediction itself rather than trusting
+        // that appended value.
+        let observed_blocks = history.gas_used_ratio.len();
+        let latest_base_fee = *history
+            .base_fee_per_gas
+            .get(observed_blocks.saturating_sub(1))
+            .ok_or_else(|| RelayerError::GasEstimation("eth_feeHistory returned no base fee data".to_string()))?;
+        let latest_gas_used_ratio = *history
+            .gas_used_ratio
+            .last()
+            .ok_or_else(|| RelayerError::GasEstimation("eth_feeHistory returned no gas used data".to_string()))?;
 
-        Ok((max_fee, priority_fee))
+        let predicted_base_fee = predict_next_base_fee(latest_base_fee, latest_gas_used_ratio);
+        let max_fee = predicted_base_fee * 2 + priority_fee;
+
+        Ok((self.cap_max_fee(max_fee), priority_fee))
+    }
+
+    /// Clamp a computed `max_fee_per_gas` to `ChainConfig::max_gas_price_gwei`
+    fn cap_max_fee(&self, max_fee: U256) -> U256 {
+        let max_gwei =
+            U256::from(self.config.read().unwrap().max_gas_price_gwei) * U256::from(1_000_000_000u64);
+        std::cmp::min(max_fee, max_gwei)
     }
 
     /// Health check
@@ -248,43 +558,62 @@ impl ChainProvider {
         match self.get_block_number().await {
             Ok(_) => true,
             Err(e) => {
-                error!("Health check failed for chain {}: {}", self.config.chain_id, e);
+                error!(
+                    "Health check failed for chain {}: {}",
+                    self.config.read().unwrap().chain_id,
+                    e
+                );
                 false
             }
         }
     }
 
+    /// Get the full chain configuration
+    pub fn config(&self) -> ChainConfig {
+        self.config.read().unwrap().clone()
+    }
+
     /// Get chain ID
     pub fn chain_id(&self) -> u64 {
-        self.config.chain_id
+        self.config.read().unwrap().chain_id
     }
 
     /// Get contract address
-    pub fn contract_address(&self) -> &str {
-        &self.config.contract_address
+    pub fn contract_address(&self) -> String {
+        self.config.read().unwrap().contract_address.clone()
     }
 
     /// Get coordinator address
-    pub fn coordinator_address(&self) -> &str {
-        &self.config.coordinator_address
+    pub fn coordinator_address(&self) -> String {
+        self.config.read().unwrap().coordinator_address.clone()
+    }
+
+    /// Get the configured gas price strategy
+    pub fn gas_price_strategy(&self) -> GasPriceStrategy {
+        self.config.read().unwrap().gas_price_strategy.clone()
     }
 
     /// Get confirmation blocks
     pub fn confirmation_blocks(&self) -> u64 {
-        self.config.confirmation_blocks
+        self.config.read().unwrap().confirmation_blocks
     }
 
     /// Reconnect WebSocket
     pub async fn reconnect_ws(&self) -> RelayerResult<()> {
-        if let Some(ref ws_url) = self.config.ws_url {
-            match Provider::<Ws>::connect(ws_url).await {
+        let (ws_url, chain_id) = {
+            let config = self.config.read().unwrap();
+            (config.ws_url.clone(), config.chain_id)
+        };
+
+        if let Some(ws_url) = ws_url {
+            match Provider::<Ws>::connect(&ws_url).await {
                 Ok(provider) => {
                     *self.ws_provider.write().await = Some(provider);
-                    info!("WebSocket reconnected for chain {}", self.config.chain_id);
+                    info!("WebSocket reconnected for chain {}", chain_id);
                     Ok(())
                 }
                 Err(e) => Err(RelayerError::ChainConnection {
-                    chain_id: self.config.chain_id,
+                    chain_id,
                     message: format!("WebSocket reconnection failed: {}", e),
                 }),
             }
@@ -292,6 +621,67 @@ impl ChainProvider {
             Ok(())
         }
     }
+
+    /// Reconcile this provider's state against a freshly-loaded `ChainConfig`
+    /// for the same chain, applied on a SIGHUP config reload. Rebuilds the
+    /// HTTP provider set if `rpc_urls` changed and reconnects the WebSocket
+    /// if `ws_url` changed. If the HTTP rebuild fails, the working state is
+    /// left untouched so a bad reload can't take a healthy chain down; if
+    /// only the WebSocket reconnect fails, the new config and HTTP providers
+    /// are already in place and the previous WebSocket connection is kept.
+    pub async fn reconcile(&self, new_config: ChainConfig) -> RelayerResult<()> {
+        let (rpc_urls_changed, ws_url_changed) = {
+            let config = self.config.read().unwrap();
+            (
+                config.rpc_urls != new_config.rpc_urls,
+                config.ws_url != new_config.ws_url,
+            )
+        };
+
+        if rpc_urls_changed {
+            let rebuilt = ProviderSet::build(&new_config)?;
+            *self.providers.write().unwrap() = rebuilt;
+            self.current_provider.store(0, Ordering::Relaxed);
+            info!(
+                "Chain {} RPC endpoints reconciled ({} providers)",
+                new_config.chain_id,
+                new_config.rpc_urls.len()
+            );
+        }
+
+        *self.config.write().unwrap() = new_config;
+
+        if ws_url_changed {
+            self.reconnect_ws().await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Applies the EIP-1559 base fee update rule to predict the next block's
+/// base fee: usage at exactly half the gas limit (the gas target) leaves
+/// the base fee unchanged, and it scales linearly up to +12.5% on a full
+/// block or -12.5% on an empty one.
+fn predict_next_base_fee(base_fee: U256, gas_used_ratio: f64) -> U256 {
+    let ratio_permille = (gas_used_ratio.clamp(0.0, 1.0) * 1000.0) as i64;
+    let diff = ratio_permille - 500;
+    let change = base_fee * U256::from(diff.unsigned_abs()) / U256::from(4000u64);
+
+    if diff >= 0 {
+        base_fee + change
+    } else {
+        base_fee.saturating_sub(change)
+    }
+}
+
+/// Count zero vs non-zero bytes in calldata, as charged differently by both
+/// Ethereum's intrinsic gas cost and the L1 data fee formulas above (4 gas
+/// per zero byte, 16 per non-zero byte)
+fn count_calldata_bytes(data: &[u8]) -> (u64, u64) {
+    let zero = data.iter().filter(|b| **b == 0).count() as u64;
+    let nonzero = data.len() as u64 - zero;
+    (zero, nonzero)
 }
 
 /// Gas price types
@@ -303,3 +693,16 @@ pub enum GasPrice {
         max_priority_fee_per_gas: U256,
     },
 }
+
+impl GasPrice {
+    /// The component that scales with gas-escalation bumps - `price` for
+    /// legacy transactions, `max_fee_per_gas` for EIP-1559 ones - used to
+    /// compare two `GasPrice`s of the same kind against each other (e.g. a
+    /// multiplier check against the original submission price).
+    pub fn primary_wei(&self) -> U256 {
+        match self {
+            GasPrice::Legacy(price) => *price,
+            GasPrice::Eip1559 { max_fee_per_gas, .. } => *max_fee_per_gas,
+        }
+    }
+}
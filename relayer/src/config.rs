@@ -28,6 +28,49 @@ pub struct RelayerConfig {
     pub max_retries: u32,
     pub retry_delay_ms: u64,
     pub health_check_interval_secs: u64,
+    /// Floor for the EIP-1559 priority fee derived from fee history, in gwei
+    pub priority_fee_floor_gwei: u64,
+    /// Ceiling for the EIP-1559 priority fee derived from fee history, in gwei
+    pub priority_fee_ceiling_gwei: u64,
+    /// Attach EIP-2930 access lists to resolve-tx when the node estimates it's cheaper
+    pub enable_access_lists: bool,
+    /// Seconds a tx may sit unconfirmed before the gas escalator bumps its fee
+    pub gas_escalation_interval_secs: u64,
+    /// Per-mille multiplier applied per escalation step (1125 = 1.125x); a
+    /// value below 1100 is raised to the 10% minimum replacement bump
+    pub gas_escalation_factor_permille: u64,
+    /// Per-mille cap on the cumulative escalation multiplier (e.g. 3000 = 3x)
+    pub gas_escalation_max_multiplier_permille: u64,
+    /// Seconds between confirmation-tracker polls of submitted transactions
+    pub confirmation_poll_interval_secs: u64,
+    /// Default cap on escalation sweeps per stuck nonce before giving up and
+    /// holding at the current price (overridable per chain)
+    pub gas_escalation_max_attempts: u32,
+    /// Default cadence the gas escalator uses to decide a stuck transaction
+    /// is due for another bump (overridable per chain)
+    #[serde(default = "default_gas_escalation_schedule")]
+    pub gas_escalation_schedule: EscalationSchedule,
+    /// Blocks a tx may sit unconfirmed before the escalator bumps its fee,
+    /// when `gas_escalation_schedule` is `per_block`
+    #[serde(default = "default_gas_escalation_interval_blocks")]
+    pub gas_escalation_interval_blocks: u64,
+    /// Maximum ready transactions queued per chain before the lowest-scored
+    /// entries are evicted
+    pub queue_capacity: usize,
+    /// Per-origin cap on queued transactions, as a percentage of
+    /// `queue_capacity` (e.g. 1 = no single origin rollup may hold more than
+    /// 1% of the queue)
+    pub queue_per_origin_cap_percent: u8,
+    /// Seconds a claimed submission job may go without a heartbeat before the
+    /// reaper requeues it for another worker to pick up
+    pub submission_job_heartbeat_timeout_secs: u64,
+    /// Seconds a submitted transaction may go without a push
+    /// `TransactionExecuted`/`TransactionFailed` event before the
+    /// eventuality poller actively checks the target chain's logs for it
+    pub eventuality_poll_age_secs: u64,
+    /// Seconds between `ConfirmationWatcher` ticks advancing the
+    /// confirmation depth of `Confirming` transactions
+    pub confirmation_watch_interval_secs: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -58,9 +101,84 @@ pub struct ChainConfig {
     pub contract_address: String,
     pub coordinator_address: String,
     pub confirmation_blocks: u64,
+    /// Strategy used to decide when a block on this chain is finalized.
+    /// `ConfirmationCount` relies on `confirmation_blocks` above; on chains
+    /// that expose a consensus-finalized checkpoint, prefer
+    /// `ConsensusFinalized` instead.
+    pub finality_backend: FinalityBackendKind,
     pub gas_price_strategy: GasPriceStrategy,
     pub max_gas_price_gwei: u64,
     pub enabled: bool,
+    /// Per-chain override of `RelayerConfig::gas_escalation_factor_permille`
+    pub gas_escalation_factor_permille: Option<u64>,
+    /// Per-chain override of `RelayerConfig::gas_escalation_max_multiplier_permille`
+    pub gas_escalation_max_multiplier_permille: Option<u64>,
+    /// Per-chain override of `RelayerConfig::gas_escalation_max_attempts`
+    pub gas_escalation_max_attempts: Option<u32>,
+    /// Per-chain override of `RelayerConfig::gas_escalation_schedule`
+    pub gas_escalation_schedule: Option<EscalationSchedule>,
+    /// Per-chain override of `RelayerConfig::gas_escalation_interval_blocks`
+    pub gas_escalation_interval_blocks: Option<u64>,
+    /// For `finality_backend = l1_anchored` rollups: the chain ID of the L1
+    /// this chain posts batches/output roots to
+    pub l1_chain_id: Option<u64>,
+    /// For `finality_backend = l1_anchored` rollups: address of the L1
+    /// contract exposing the highest L2 block it has durably confirmed
+    /// (e.g. Optimism's `L2OutputOracle`, Arbitrum's `Rollup`)
+    pub l1_batch_contract_address: Option<String>,
+    /// For `finality_backend = l1_anchored` rollups: 4-byte selector (hex,
+    /// e.g. "0x4599c788") of a zero-argument view on
+    /// `l1_batch_contract_address` returning that confirmed L2 block number
+    /// as a `uint256`. The exact function differs per rollup, so it's
+    /// configured rather than hardcoded to one ABI.
+    pub l1_confirmed_block_selector: Option<String>,
+    /// External gas-price trackers to query before falling back to
+    /// `ChainProvider::get_gas_price` (see `tx::gas_oracle`). Empty means
+    /// use on-chain estimation only.
+    #[serde(default)]
+    pub gas_oracles: Vec<OracleConfig>,
+    /// Which speed tier to request from `gas_oracles`
+    #[serde(default = "default_gas_category")]
+    pub gas_category: GasCategory,
+    /// Number of recent blocks `ChainProvider::estimate_eip1559_fees` pulls
+    /// from `eth_feeHistory` for its priority-fee estimate
+    #[serde(default = "default_fee_history_lookback_blocks")]
+    pub fee_history_lookback_blocks: u64,
+    /// Reward percentile (0-100) requested from `eth_feeHistory` for the
+    /// suggested priority fee
+    #[serde(default = "default_fee_history_reward_percentile")]
+    pub fee_history_reward_percentile: f64,
+}
+
+fn default_gas_category() -> GasCategory {
+    GasCategory::Standard
+}
+
+fn default_fee_history_lookback_blocks() -> u64 {
+    20
+}
+
+fn default_fee_history_reward_percentile() -> f64 {
+    40.0
+}
+
+fn default_gas_escalation_schedule() -> EscalationSchedule {
+    EscalationSchedule::EverySecs
+}
+
+fn default_gas_escalation_interval_blocks() -> u64 {
+    3
+}
+
+/// Cadence `GasEscalator` uses to decide when a stuck transaction is due for
+/// another gas bump
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EscalationSchedule {
+    /// Bump every `gas_escalation_interval_secs` of wall-clock time
+    EverySecs,
+    /// Bump every `gas_escalation_interval_blocks` new blocks observed on chain
+    PerBlock,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -72,10 +190,68 @@ pub enum GasPriceStrategy {
     Optimism,
 }
 
+/// Selects which [`crate::chain::finality::FinalityBackend`] a chain uses
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinalityBackendKind {
+    /// Wait `confirmation_blocks` blocks past inclusion
+    ConfirmationCount,
+    /// Trust the node's consensus-finalized (`finalized` tag) checkpoint
+    ConsensusFinalized,
+    /// Optimistic-rollup mode: trust the parent (L1) chain's batch-posting
+    /// contract, read as of L1's own finalized checkpoint
+    L1Anchored,
+}
+
+/// One external gas-price tracker entry in `ChainConfig::gas_oracles`
+#[derive(Debug, Clone, Deserialize)]
+pub struct OracleConfig {
+    pub kind: GasOracleKind,
+    pub url: String,
+    /// Sent as a bearer token where the tracker requires one (e.g. Blocknative)
+    pub api_key: Option<String>,
+    /// Relative weight in `MedianGasOracle`'s aggregation - this oracle's
+    /// result is counted `weight` times before taking the median, so a
+    /// trusted source can be given more say without floating-point
+    /// interpolation. Most deployments should leave this at 1.
+    #[serde(default = "default_oracle_weight")]
+    pub weight: u32,
+}
+
+fn default_oracle_weight() -> u32 {
+    1
+}
+
+/// Which external gas-price tracker API `OracleConfig::kind` speaks
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GasOracleKind {
+    /// `{safeLow, standard, fast, fastest, currentBaseFee}` gwei floats
+    Etherchain,
+    /// Blocknative's `blockprices` confidence-tiered gwei estimates
+    Blocknative,
+}
+
+/// A speed tier requested from a [`crate::tx::gas_oracle::GasOracle`]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GasCategory {
+    SafeLow,
+    Standard,
+    Fast,
+    Fastest,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct WalletConfig {
     pub keystore_path: Option<String>,
     pub private_key_env: Option<String>,
+    /// Sign with a hardware Ledger wallet instead of a software key.
+    /// Takes priority over `keystore_path` and `private_key_env`.
+    #[serde(default)]
+    pub use_ledger: bool,
+    /// BIP-44 account index for the Ledger Live derivation path (default 0)
+    pub ledger_account_index: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -0,0 +1,135 @@
+//! Confirmation-depth watcher
+//!
+//! `mark_finalized_with_provenance` (see `engine.rs`) only moves a
+//! transaction into `TransactionState::Confirming` the instant its
+//! `TransactionExecuted` event is seen - at that point it has survived
+//! zero confirmations. This watcher runs alongside the engine, and on each
+//! tick first hands every `Confirming` transaction on the chain to that
+//! chain's `FinalityTracker`, so its configured backend (consensus
+//! `finalized` checkpoint, L1-anchored rollup batch, or plain
+//! confirmation count) gets to decide finality directly - a backend like
+//! `ConsensusFinalizedBackend` can clear a transaction in one tick
+//! regardless of raw block depth. Anything the backend hasn't finalized
+//! yet still falls back to block-depth counting (via
+//! `DependencyGraph::advance_confirmation`) once it reaches that chain's
+//! `confirmation_blocks`, so a chain with no pluggable backend configured
+//! behaves exactly as before.
+
+use super::dependency::DependencyGraph;
+use crate::chain::ChainManager;
+use crate::config::RelayerConfig;
+
+use dashmap::DashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::warn;
+
+/// Advances confirmation depth for transactions awaiting finalization
+pub struct ConfirmationWatcher {
+    chain_manager: Arc<ChainManager>,
+    dependency_graph: Arc<DependencyGraph>,
+    config: RelayerConfig,
+    /// Block height each chain was at on the previous tick, so this tick
+    /// only credits newly observed blocks
+    last_seen_block: DashMap<u64, u64>,
+    shutdown: Arc<RwLock<bool>>,
+}
+
+impl ConfirmationWatcher {
+    pub fn new(chain_manager: Arc<ChainManager>, dependency_graph: Arc<DependencyGraph>, config: RelayerConfig) -> Self {
+        Self {
+            chain_manager,
+            dependency_graph,
+            config,
+            last_seen_block: DashMap::new(),
+            shutdown: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    /// Run the watch loop until `stop()` is called
+    pub async fn run(&self) {
+        let poll_interval =
+            std::time::Duration::from_secs(self.config.confirmation_watch_interval_secs.max(1));
+        let mut ticker = tokio::time::interval(poll_interval);
+
+        loop {
+            if *self.shutdown.read().await {
+                break;
+            }
+            ticker.tick().await;
+            self.tick().await;
+        }
+    }
+
+    /// Stop the watch loop
+    pub async fn stop(&self) {
+        *self.shutdown.write().await = true;
+    }
+
+    async fn tick(&self) {
+        for chain_id in self.chain_manager.connected_chains() {
+            if let Err(e) = self.tick_chain(chain_id).await {
+                warn!("Confirmation watch failed for chain {}: {}", chain_id, e);
+            }
+        }
+    }
+
+    async fn tick_chain(&self, chain_id: u64) -> crate::error::RelayerResult<()> {
+        let provider = self.chain_manager.get_provider(chain_id)?;
+        let current_block = provider.get_block_number().await?;
+
+        let new_blocks = match self.last_seen_block.insert(chain_id, current_block) {
+            Some(previous) => current_block.saturating_sub(previous),
+            // First tick for this chain - nothing to credit yet, just
+            // establish a baseline.
+            None => 0,
+        };
+
+        if new_blocks == 0 {
+            return Ok(());
+        }
+
+        if let Ok(finality_tracker) = self.chain_manager.get_finality_tracker(chain_id) {
+            // `FinalityTracker` is keyed by the real EVM `tx_hash`, not the
+            // application-level `tx_id`, so remember the mapping back to
+            // `tx_id` here - `check_pending` only gives us hashes.
+            let mut tx_id_by_hash = std::collections::HashMap::new();
+            for tx in self.dependency_graph.get_confirming_for_chain(chain_id).await {
+                if let Some(provenance) = self.dependency_graph.get_execution_provenance(&tx.tx_id).await {
+                    tx_id_by_hash.insert(provenance.tx_hash, tx.tx_id);
+                    finality_tracker
+                        .track(provenance.tx_hash, provenance.block_number)
+                        .await;
+                }
+            }
+
+            match finality_tracker.check_pending().await {
+                Ok(finalized) => {
+                    for tx_hash in finalized {
+                        let Some(tx_id) = tx_id_by_hash.get(&tx_hash) else {
+                            continue;
+                        };
+                        if let Some(provenance) =
+                            self.dependency_graph.get_execution_provenance(tx_id).await
+                        {
+                            self.dependency_graph.mark_finalized(tx_id, provenance).await;
+                        }
+                    }
+                }
+                Err(e) => warn!(
+                    "Finality backend check failed for chain {}: {}",
+                    chain_id, e
+                ),
+            }
+        }
+
+        let required_confirmations = provider.confirmation_blocks() as u32;
+        for tx in self.dependency_graph.get_confirming_for_chain(chain_id).await {
+            self.dependency_graph
+                .advance_confirmation(&tx.tx_id, new_blocks as u32, required_confirmations)
+                .await;
+        }
+
+        Ok(())
+    }
+}
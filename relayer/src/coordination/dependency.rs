@@ -1,13 +1,28 @@
 //! Dependency graph for tracking cross-chain transaction dependencies
 
+use crate::error::RelayerResult;
+use crate::state::StateManager;
+
+use ethers::types::{Address, H256};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::warn;
 
 /// Transaction dependency tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingTransaction {
     pub tx_id: [u8; 32],
     pub origin_chain: u64,
+    /// Rollup address the transaction originated from, used to cap how much
+    /// of the submission queue a single origin can occupy
+    pub origin: Address,
+    /// Block number on `origin_chain` the `TransactionBuffered` event was
+    /// observed at, so a reorg on that chain can scope which transactions
+    /// need to revert back to `Buffered`
+    pub origin_block: u64,
     pub target_chain: u64,
     pub dependency_id: Option<[u8; 32]>,
     pub swap_group_id: Option<[u8; 32]>,
@@ -15,17 +30,113 @@ pub struct PendingTransaction {
     pub created_at: u64,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransactionState {
     Buffered,
     DependencyPending,
     Ready,
     Submitted,
+    /// Its `TransactionExecuted` event has been observed, but it hasn't yet
+    /// survived `ChainConfig::confirmation_blocks` confirmations - dependents
+    /// stay gated until `ConfirmationWatcher` promotes it to `Finalized`
+    Confirming {
+        confirmations: u32,
+    },
     Finalized,
     Failed,
     Expired,
 }
 
+/// The block a transaction's `TransactionExecuted` event was observed in,
+/// so a later reorg of that block can be detected and the finalization
+/// unwound. Borrows the TreeRoute/ImportRoute model from Ethereum clients:
+/// a block is provenance until proven otherwise by a reorg past it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExecutionProvenance {
+    pub chain_id: u64,
+    pub block_number: u64,
+    pub block_hash: H256,
+    /// The real EVM transaction hash from the `TransactionExecuted` log -
+    /// distinct from `tx_id`, the contract's application-level identifier -
+    /// so `FinalityTracker` can look up a receipt for it.
+    pub tx_hash: H256,
+}
+
+/// An append-only record of a single mutation to a `DependencyGraph`.
+/// Every mutating method on `DependencyGraph` persists one of these through
+/// `StateManager` before updating its in-memory projection, so the graph can
+/// be rebuilt deterministically by replaying the log (see
+/// `DependencyGraph::replay`) instead of trusting a point-in-time snapshot -
+/// a crash between an event committing and the projection update it
+/// describes is recovered by simply re-applying that event on next startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DependencyEvent {
+    TransactionAdded(PendingTransaction),
+    DependencyRecorded {
+        tx_id: [u8; 32],
+        dependency_id: [u8; 32],
+    },
+    SwapGroupJoined {
+        tx_id: [u8; 32],
+        group_id: [u8; 32],
+    },
+    StateChanged {
+        tx_id: [u8; 32],
+        from: TransactionState,
+        to: TransactionState,
+        /// Set only when `to` is `Confirming` or `Finalized`, so a reorg-reversal replay can
+        /// restore `execution_provenance` alongside the state transition.
+        provenance: Option<ExecutionProvenance>,
+    },
+    DependentsNotified {
+        resolved_tx_id: [u8; 32],
+        notified: Vec<[u8; 32]>,
+    },
+}
+
+impl DependencyEvent {
+    /// The transaction this event is about, used as the log's partition key
+    pub(crate) fn tx_id(&self) -> [u8; 32] {
+        match self {
+            DependencyEvent::TransactionAdded(tx) => tx.tx_id,
+            DependencyEvent::DependencyRecorded { tx_id, .. } => *tx_id,
+            DependencyEvent::SwapGroupJoined { tx_id, .. } => *tx_id,
+            DependencyEvent::StateChanged { tx_id, .. } => *tx_id,
+            DependencyEvent::DependentsNotified { resolved_tx_id, .. } => *resolved_tx_id,
+        }
+    }
+
+    /// Short name stored in `dependency_events.event_type`, purely for
+    /// operators eyeballing the table - replay only ever reads `event_data`
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            DependencyEvent::TransactionAdded(_) => "transaction_added",
+            DependencyEvent::DependencyRecorded { .. } => "dependency_recorded",
+            DependencyEvent::SwapGroupJoined { .. } => "swap_group_joined",
+            DependencyEvent::StateChanged { .. } => "state_changed",
+            DependencyEvent::DependentsNotified { .. } => "dependents_notified",
+        }
+    }
+
+    /// Whether this event left its transaction in a state that can never
+    /// change again, making it (and every earlier event for the same
+    /// `tx_id`) safe to drop once `StateManager::compact_dependency_events`
+    /// runs - a reorg unwinds `Finalized` via fresh `StateChanged` events of
+    /// its own rather than resurrecting old ones, so this is still safe to
+    /// compact away.
+    pub(crate) fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            DependencyEvent::StateChanged {
+                to: TransactionState::Finalized
+                    | TransactionState::Failed
+                    | TransactionState::Expired,
+                ..
+            }
+        )
+    }
+}
+
 /// Dependency graph for managing cross-chain transaction relationships
 pub struct DependencyGraph {
     /// All tracked transactions
@@ -34,90 +145,408 @@ pub struct DependencyGraph {
     dependents: RwLock<HashMap<[u8; 32], HashSet<[u8; 32]>>>,
     /// Swap groups: group_id -> set of transaction IDs
     swap_groups: RwLock<HashMap<[u8; 32], HashSet<[u8; 32]>>>,
+    /// Where each `Finalized` transaction's executing block was observed,
+    /// so `handle_reorg` can tell which finalizations a reorg invalidated
+    execution_provenance: RwLock<HashMap<[u8; 32], ExecutionProvenance>>,
+    /// Durable append-only log every mutation is persisted through before
+    /// being applied to the in-memory maps above
+    state_manager: Arc<StateManager>,
 }
 
 impl DependencyGraph {
-    pub fn new() -> Self {
+    pub fn new(state_manager: Arc<StateManager>) -> Self {
         Self {
             transactions: RwLock::new(HashMap::new()),
             dependents: RwLock::new(HashMap::new()),
             swap_groups: RwLock::new(HashMap::new()),
+            execution_provenance: RwLock::new(HashMap::new()),
+            state_manager,
+        }
+    }
+
+    /// Rebuild the graph from durable state: the compacted
+    /// `pending_transactions` snapshot as a baseline (cheap, and already
+    /// excludes terminal transactions), then every `dependency_events` row
+    /// replayed in order on top of it. Replay is what actually makes
+    /// recovery deterministic after a crash mid-transition, since the
+    /// snapshot alone can't reflect a mutation whose event committed but
+    /// whose in-memory projection never got applied before the crash.
+    pub async fn replay(&self) -> RelayerResult<()> {
+        for tx in self.state_manager.get_pending_transactions().await? {
+            self.apply(DependencyEvent::TransactionAdded(tx)).await;
+        }
+
+        for event in self.state_manager.load_dependency_events().await? {
+            self.apply(event).await;
+        }
+
+        Ok(())
+    }
+
+    /// Persist `event` to the durable log. Best-effort: a write failure is
+    /// logged and swallowed rather than aborting the in-memory mutation, the
+    /// same degrade-gracefully posture `mark_finalized_with_provenance`
+    /// already takes on the block-hash lookup it feeds into this log.
+    async fn emit(&self, event: &DependencyEvent) {
+        if let Err(e) = self.state_manager.append_dependency_event(event).await {
+            warn!(
+                "Failed to persist dependency event {} for {}: {}",
+                event.name(),
+                hex::encode(event.tx_id()),
+                e
+            );
+        }
+    }
+
+    /// Apply an already-(or about-to-be-)persisted event to the in-memory
+    /// projection. Shared by every mutating method and by `replay`, so the
+    /// projection logic only exists once.
+    async fn apply(&self, event: DependencyEvent) {
+        match event {
+            DependencyEvent::TransactionAdded(tx) => {
+                self.transactions.write().await.insert(tx.tx_id, tx);
+            }
+            DependencyEvent::DependencyRecorded { tx_id, dependency_id } => {
+                self.dependents
+                    .write()
+                    .await
+                    .entry(dependency_id)
+                    .or_insert_with(HashSet::new)
+                    .insert(tx_id);
+            }
+            DependencyEvent::SwapGroupJoined { tx_id, group_id } => {
+                self.swap_groups
+                    .write()
+                    .await
+                    .entry(group_id)
+                    .or_insert_with(HashSet::new)
+                    .insert(tx_id);
+            }
+            DependencyEvent::StateChanged { tx_id, to, provenance, .. } => {
+                if let Some(tx) = self.transactions.write().await.get_mut(&tx_id) {
+                    tx.state = to;
+                }
+                if let Some(provenance) = provenance {
+                    self.execution_provenance.write().await.insert(tx_id, provenance);
+                }
+            }
+            DependencyEvent::DependentsNotified { notified, .. } => {
+                let mut txs = self.transactions.write().await;
+                for tx_id in notified {
+                    if let Some(tx) = txs.get_mut(&tx_id) {
+                        tx.state = TransactionState::Ready;
+                    }
+                }
+            }
         }
     }
 
-    /// Add a new transaction to track
+    /// Persist and apply a `StateChanged` event from whatever state `tx_id`
+    /// is currently in to `to`. A no-op if `tx_id` isn't tracked.
+    async fn transition(
+        &self,
+        tx_id: &[u8; 32],
+        to: TransactionState,
+        provenance: Option<ExecutionProvenance>,
+    ) {
+        let from = match self.transactions.read().await.get(tx_id) {
+            Some(tx) => tx.state.clone(),
+            None => return,
+        };
+
+        let event = DependencyEvent::StateChanged {
+            tx_id: *tx_id,
+            from,
+            to,
+            provenance,
+        };
+        self.emit(&event).await;
+        self.apply(event).await;
+        self.persist_state(tx_id).await;
+    }
+
+    /// Write a tracked transaction's current in-memory state through to its
+    /// `pending_transactions` row and fire the `tx_state` NOTIFY, so
+    /// `get_pending_transactions` (the `replay()` baseline) and LISTEN/NOTIFY
+    /// subscribers both see every lifecycle transition, not just creation.
+    /// `dependency_events` stays the source of truth replay rebuilds from -
+    /// this is the read-path projection replay itself doesn't need, kept in
+    /// sync best-effort the same way `emit` is.
+    async fn persist_state(&self, tx_id: &[u8; 32]) {
+        let Some(tx) = self.transactions.read().await.get(tx_id).cloned() else {
+            return;
+        };
+        if let Err(e) = self.state_manager.store_pending_transaction(&tx).await {
+            warn!(
+                "Failed to persist state {:?} for {}: {}",
+                tx.state,
+                hex::encode(tx_id),
+                e
+            );
+        }
+    }
+
+    /// Add a new transaction to track. A no-op if `tx_id` is already
+    /// tracked - a redelivered `TransactionBuffered` (e.g. a backfill
+    /// overlapping the live subscription after a reconnect) must not
+    /// clobber a transaction that has since progressed past `Buffered`
+    /// back down to its freshly-constructed, always-`Buffered` state.
     pub async fn add_transaction(&self, tx: PendingTransaction) {
+        if self.transactions.read().await.contains_key(&tx.tx_id) {
+            return;
+        }
+
         let tx_id = tx.tx_id;
         let dependency_id = tx.dependency_id;
         let swap_group_id = tx.swap_group_id;
 
-        // Store transaction
-        self.transactions.write().await.insert(tx_id, tx);
+        let added = DependencyEvent::TransactionAdded(tx);
+        self.emit(&added).await;
+        self.apply(added).await;
 
-        // Track dependency relationship
-        if let Some(dep_id) = dependency_id {
-            self.dependents
-                .write()
-                .await
-                .entry(dep_id)
-                .or_insert_with(HashSet::new)
-                .insert(tx_id);
+        if let Some(dependency_id) = dependency_id {
+            let recorded = DependencyEvent::DependencyRecorded { tx_id, dependency_id };
+            self.emit(&recorded).await;
+            self.apply(recorded).await;
         }
 
-        // Track swap group membership
         if let Some(group_id) = swap_group_id {
-            self.swap_groups
-                .write()
-                .await
-                .entry(group_id)
-                .or_insert_with(HashSet::new)
-                .insert(tx_id);
+            let joined = DependencyEvent::SwapGroupJoined { tx_id, group_id };
+            self.emit(&joined).await;
+            self.apply(joined).await;
         }
     }
 
     /// Mark a transaction as ready (dependency resolved)
     pub async fn mark_ready(&self, tx_id: &[u8; 32]) {
-        if let Some(tx) = self.transactions.write().await.get_mut(tx_id) {
-            tx.state = TransactionState::Ready;
-        }
+        self.transition(tx_id, TransactionState::Ready, None).await;
     }
 
     /// Mark a transaction as submitted
     pub async fn mark_submitted(&self, tx_id: &[u8; 32]) {
-        if let Some(tx) = self.transactions.write().await.get_mut(tx_id) {
-            tx.state = TransactionState::Submitted;
-        }
+        self.transition(tx_id, TransactionState::Submitted, None).await;
     }
 
-    /// Mark a transaction as finalized
-    pub async fn mark_finalized(&self, tx_id: &[u8; 32]) {
-        if let Some(tx) = self.transactions.write().await.get_mut(tx_id) {
-            tx.state = TransactionState::Finalized;
+    /// Move a transaction into `Confirming`, anchoring it to the block its
+    /// `TransactionExecuted` event was observed in. This is the same
+    /// provenance `handle_reorg` already uses for `Finalized` transactions,
+    /// recorded a stage earlier so a reorg underneath a transaction still
+    /// accumulating confirmations is caught too.
+    pub async fn mark_confirming(&self, tx_id: &[u8; 32], provenance: ExecutionProvenance) {
+        self.transition(
+            tx_id,
+            TransactionState::Confirming { confirmations: 0 },
+            Some(provenance),
+        )
+        .await;
+    }
+
+    /// Credit `by` newly observed blocks toward a transaction's confirmation
+    /// count, promoting it to `Finalized` (and notifying dependents) once
+    /// `required_confirmations` is reached. Returns the transaction's new
+    /// confirmation count, or `None` if it isn't currently `Confirming`.
+    pub async fn advance_confirmation(
+        &self,
+        tx_id: &[u8; 32],
+        by: u32,
+        required_confirmations: u32,
+    ) -> Option<u32> {
+        let current = match self.transactions.read().await.get(tx_id).map(|tx| tx.state.clone()) {
+            Some(TransactionState::Confirming { confirmations }) => confirmations,
+            _ => return None,
+        };
+
+        let next = current.saturating_add(by);
+        if next >= required_confirmations {
+            match self.execution_provenance.read().await.get(tx_id).copied() {
+                Some(provenance) => self.mark_finalized(tx_id, provenance).await,
+                None => {
+                    // Shouldn't happen - `mark_confirming` always records an
+                    // anchor - but finalize anyway rather than stalling here.
+                    self.transition(tx_id, TransactionState::Finalized, None).await;
+                    self.notify_dependents(tx_id).await;
+                }
+            }
+        } else {
+            self.transition(tx_id, TransactionState::Confirming { confirmations: next }, None)
+                .await;
         }
 
+        Some(next)
+    }
+
+    /// Mark a transaction as finalized, recording the block its
+    /// `TransactionExecuted` event was observed in so a later reorg of that
+    /// block can be caught by `handle_reorg`
+    pub async fn mark_finalized(&self, tx_id: &[u8; 32], provenance: ExecutionProvenance) {
+        self.transition(tx_id, TransactionState::Finalized, Some(provenance)).await;
+
         // Notify dependents
         self.notify_dependents(tx_id).await;
     }
 
     /// Mark a transaction as failed
     pub async fn mark_failed(&self, tx_id: &[u8; 32]) {
-        if let Some(tx) = self.transactions.write().await.get_mut(tx_id) {
-            tx.state = TransactionState::Failed;
+        self.transition(tx_id, TransactionState::Failed, None).await;
+    }
+
+    /// Mark a transaction as expired (e.g. evicted from a capacity-bounded queue)
+    pub async fn mark_expired(&self, tx_id: &[u8; 32]) {
+        self.transition(tx_id, TransactionState::Expired, None).await;
+    }
+
+    /// Revert a transaction back to `Buffered` (e.g. a reorg on its origin
+    /// chain retracted the block its `TransactionBuffered` event came from)
+    pub async fn mark_buffered(&self, tx_id: &[u8; 32]) {
+        self.transition(tx_id, TransactionState::Buffered, None).await;
+    }
+
+    /// Unwind every finalization on `chain_id` whose executing block is
+    /// above `common_ancestor` (i.e. was retracted by the reorg): the
+    /// transaction itself reverts from `Finalized` back to `Ready` (or
+    /// `DependencyPending` if its own dependency no longer holds), and the
+    /// `dependents` DAG is walked so any dependent promoted to `Ready`
+    /// solely by the now-undone finalization is demoted back to
+    /// `DependencyPending`. Returns the IDs of every transaction touched,
+    /// for logging.
+    pub async fn handle_reorg(&self, chain_id: u64, common_ancestor: u64) -> Vec<[u8; 32]> {
+        let retracted: Vec<[u8; 32]> = self
+            .execution_provenance
+            .read()
+            .await
+            .iter()
+            .filter(|(_, p)| p.chain_id == chain_id && p.block_number > common_ancestor)
+            .map(|(tx_id, _)| *tx_id)
+            .collect();
+
+        if retracted.is_empty() {
+            return Vec::new();
         }
+
+        let mut visited = HashSet::new();
+        let mut reverted = Vec::new();
+
+        for tx_id in retracted {
+            self.execution_provenance.write().await.remove(&tx_id);
+            self.revert_finalization(&tx_id, &mut visited, &mut reverted).await;
+        }
+
+        for _ in &reverted {
+            crate::metrics::record_dependency_state_reverted(chain_id);
+        }
+
+        reverted
     }
 
-    /// Get transactions waiting on a dependency
-    async fn notify_dependents(&self, resolved_tx_id: &[u8; 32]) {
-        let dependents = self.dependents.read().await;
-        if let Some(waiting) = dependents.get(resolved_tx_id) {
-            let mut txs = self.transactions.write().await;
-            for waiting_tx_id in waiting {
-                if let Some(tx) = txs.get_mut(waiting_tx_id) {
-                    if tx.state == TransactionState::DependencyPending {
-                        tx.state = TransactionState::Ready;
-                    }
+    /// Revert a single `Finalized` transaction back to `Ready`/`DependencyPending`,
+    /// then recursively demote any dependent that was only promoted to
+    /// `Ready` because of it. `visited` guards the walk against the
+    /// `dependents` map being (or becoming) cyclic.
+    fn revert_finalization<'a>(
+        &'a self,
+        tx_id: &'a [u8; 32],
+        visited: &'a mut HashSet<[u8; 32]>,
+        reverted: &'a mut Vec<[u8; 32]>,
+    ) -> std::pin::Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if !visited.insert(*tx_id) {
+                return;
+            }
+
+            let dependency_still_finalized = {
+                let txs = self.transactions.read().await;
+                txs.get(tx_id)
+                    .and_then(|tx| tx.dependency_id)
+                    .map(|dep_id| {
+                        matches!(
+                            txs.get(&dep_id).map(|dep| &dep.state),
+                            Some(TransactionState::Finalized)
+                        )
+                    })
+                    .unwrap_or(true) // no dependency at all - nothing to wait on
+            };
+
+            // A transaction still accumulating confirmations is just as
+            // exposed to a reorg as one that already crossed the finality
+            // threshold - its anchor points at the same retracted block.
+            let was_included = {
+                let txs = self.transactions.read().await;
+                matches!(
+                    txs.get(tx_id).map(|tx| &tx.state),
+                    Some(TransactionState::Finalized) | Some(TransactionState::Confirming { .. })
+                )
+            };
+
+            if was_included {
+                let to = if dependency_still_finalized {
+                    TransactionState::Ready
+                } else {
+                    TransactionState::DependencyPending
+                };
+                self.transition(tx_id, to, None).await;
+                reverted.push(*tx_id);
+            }
+
+            let dependents: Vec<[u8; 32]> = self
+                .dependents
+                .read()
+                .await
+                .get(tx_id)
+                .map(|set| set.iter().copied().collect())
+                .unwrap_or_default();
+
+            for dependent_id in dependents {
+                let was_ready = {
+                    let txs = self.transactions.read().await;
+                    matches!(txs.get(&dependent_id).map(|tx| &tx.state), Some(TransactionState::Ready))
+                };
+
+                if was_ready {
+                    self.transition(&dependent_id, TransactionState::DependencyPending, None).await;
+                    reverted.push(dependent_id);
+                    self.revert_finalization(&dependent_id, visited, reverted).await;
                 }
             }
+        })
+    }
+
+    /// Get transactions waiting on a dependency, persist and apply a
+    /// `DependentsNotified` event listing which of them actually promoted
+    /// from `DependencyPending` to `Ready`
+    async fn notify_dependents(&self, resolved_tx_id: &[u8; 32]) {
+        let waiting: Vec<[u8; 32]> = self
+            .dependents
+            .read()
+            .await
+            .get(resolved_tx_id)
+            .map(|set| set.iter().copied().collect())
+            .unwrap_or_default();
+
+        let notified: Vec<[u8; 32]> = {
+            let txs = self.transactions.read().await;
+            waiting
+                .into_iter()
+                .filter(|tx_id| {
+                    matches!(
+                        txs.get(tx_id).map(|tx| &tx.state),
+                        Some(TransactionState::DependencyPending)
+                    )
+                })
+                .collect()
+        };
+
+        if notified.is_empty() {
+            return;
+        }
+
+        let event = DependencyEvent::DependentsNotified {
+            resolved_tx_id: *resolved_tx_id,
+            notified: notified.clone(),
+        };
+        self.emit(&event).await;
+        self.apply(event).await;
+        for tx_id in &notified {
+            self.persist_state(tx_id).await;
         }
     }
 
@@ -134,6 +563,21 @@ impl DependencyGraph {
             .collect()
     }
 
+    /// Get all transactions on a chain still accumulating confirmations,
+    /// for `ConfirmationWatcher` to advance on each new block
+    pub async fn get_confirming_for_chain(&self, target_chain: u64) -> Vec<PendingTransaction> {
+        self.transactions
+            .read()
+            .await
+            .values()
+            .filter(|tx| {
+                tx.target_chain == target_chain
+                    && matches!(tx.state, TransactionState::Confirming { .. })
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Get all transactions in a swap group
     pub async fn get_swap_group(&self, group_id: &[u8; 32]) -> Vec<PendingTransaction> {
         let group_txs = self.swap_groups.read().await;
@@ -187,6 +631,13 @@ impl DependencyGraph {
         self.transactions.read().await.get(tx_id).cloned()
     }
 
+    /// Get the execution block a transaction was anchored to, for callers
+    /// (e.g. `ConfirmationWatcher`) that need to hand it to a chain's
+    /// `FinalityTracker` without duplicating that bookkeeping themselves
+    pub async fn get_execution_provenance(&self, tx_id: &[u8; 32]) -> Option<ExecutionProvenance> {
+        self.execution_provenance.read().await.get(tx_id).copied()
+    }
+
     /// Get all pending transactions
     pub async fn get_pending(&self) -> Vec<PendingTransaction> {
         self.transactions
@@ -207,8 +658,122 @@ impl DependencyGraph {
     }
 }
 
-impl Default for DependencyGraph {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DatabaseConfig;
+
+    /// `DependencyGraph` persists every mutation through `StateManager`, so
+    /// these tests only run when pointed at a real Postgres via
+    /// `DATABASE_URL` - skipped rather than faked out when it isn't set, the
+    /// same opt-in other sqlx-backed suites use.
+    async fn test_graph() -> Option<DependencyGraph> {
+        let url = std::env::var("DATABASE_URL").ok()?;
+        let config = DatabaseConfig {
+            url,
+            max_connections: 5,
+            min_connections: 1,
+        };
+        let state_manager = StateManager::new(&config)
+            .await
+            .expect("connect to test database");
+        Some(DependencyGraph::new(Arc::new(state_manager)))
+    }
+
+    fn tx(id: u8, dependency_id: Option<[u8; 32]>) -> PendingTransaction {
+        PendingTransaction {
+            tx_id: [id; 32],
+            origin_chain: 1,
+            origin: Address::zero(),
+            origin_block: 100,
+            target_chain: 2,
+            dependency_id,
+            swap_group_id: None,
+            state: TransactionState::DependencyPending,
+            created_at: 0,
+        }
+    }
+
+    fn provenance(block_number: u64) -> ExecutionProvenance {
+        ExecutionProvenance {
+            chain_id: 2,
+            block_number,
+            block_hash: H256::zero(),
+            tx_hash: H256::zero(),
+        }
+    }
+
+    /// A -> B -> C. A and B are both finalized on the chain the reorg hits;
+    /// C was only promoted to `Ready` by B's finalization and never
+    /// finalized itself, so it has no anchor of its own in
+    /// `execution_provenance` - its reversion can only come from the
+    /// dependents walk `revert_finalization` does while unwinding B, not
+    /// from `handle_reorg`'s direct retracted-anchor scan.
+    #[tokio::test]
+    async fn handle_reorg_cascades_through_a_multi_hop_dependency_chain() {
+        let Some(graph) = test_graph().await else {
+            eprintln!("skipping handle_reorg_cascades_through_a_multi_hop_dependency_chain: DATABASE_URL not set");
+            return;
+        };
+
+        let a = tx(1, None);
+        let b = tx(2, Some(a.tx_id));
+        let c = tx(3, Some(b.tx_id));
+        graph.add_transaction(a.clone()).await;
+        graph.add_transaction(b.clone()).await;
+        graph.add_transaction(c.clone()).await;
+
+        // Finalizing A auto-promotes B to Ready via `notify_dependents`;
+        // finalizing B then does the same for C, but C itself is left
+        // Ready - never finalized, so never anchored.
+        graph.mark_finalized(&a.tx_id, provenance(200)).await;
+        assert_eq!(
+            graph.get_transaction(&b.tx_id).await.unwrap().state,
+            TransactionState::Ready
+        );
+        graph.mark_finalized(&b.tx_id, provenance(150)).await;
+        assert_eq!(
+            graph.get_transaction(&c.tx_id).await.unwrap().state,
+            TransactionState::Ready
+        );
+
+        // Reorg retracts both A's (200) and B's (150) anchors.
+        let reverted = graph.handle_reorg(2, 140).await;
+
+        assert!(reverted.contains(&a.tx_id));
+        assert!(reverted.contains(&b.tx_id));
+        assert!(reverted.contains(&c.tx_id));
+
+        let a_after = graph.get_transaction(&a.tx_id).await.unwrap();
+        assert_eq!(a_after.state, TransactionState::Ready);
+        // B ends up DependencyPending whichever of A or B is unwound first
+        // (both were retracted in the same reorg): unwound first, B sees A
+        // still finalized and goes to Ready, then A's dependents walk
+        // demotes it; unwound second, B sees A no longer finalized. C is
+        // reachable solely through the dependents walk off of B.
+        let b_after = graph.get_transaction(&b.tx_id).await.unwrap();
+        assert_eq!(b_after.state, TransactionState::DependencyPending);
+        let c_after = graph.get_transaction(&c.tx_id).await.unwrap();
+        assert_eq!(c_after.state, TransactionState::DependencyPending);
+    }
+
+    /// A reorg that only retracts a leaf transaction's block must not touch
+    /// transactions anchored to blocks the reorg didn't reach.
+    #[tokio::test]
+    async fn handle_reorg_leaves_unaffected_finalizations_alone() {
+        let Some(graph) = test_graph().await else {
+            eprintln!("skipping handle_reorg_leaves_unaffected_finalizations_alone: DATABASE_URL not set");
+            return;
+        };
+
+        let a = tx(11, None);
+        graph.add_transaction(a.clone()).await;
+        graph.mark_finalized(&a.tx_id, provenance(300)).await;
+
+        let reverted = graph.handle_reorg(2, 300).await;
+
+        assert!(reverted.is_empty());
+        let a_after = graph.get_transaction(&a.tx_id).await.unwrap();
+        assert_eq!(a_after.state, TransactionState::Finalized);
     }
 }
@@ -1,13 +1,17 @@
 //! Main coordination engine for cross-chain transaction orchestration
 
-use super::dependency::{DependencyGraph, PendingTransaction, TransactionState};
+use super::confirmation_watcher::ConfirmationWatcher;
+use super::dependency::{DependencyGraph, ExecutionProvenance, PendingTransaction, TransactionState};
+use super::eventuality::{EventualityTracker, Outcome};
+use super::queue::PriorityQueue;
 use crate::chain::ChainManager;
-use crate::config::RelayerConfig;
+use crate::config::{RelayerConfig, WalletConfig};
 use crate::error::{RelayerError, RelayerResult};
 use crate::events::ContractEvent;
 use crate::state::StateManager;
-use crate::tx::TransactionSender;
+use crate::tx::{ConfirmationTracker, GasEscalator, Scheduler, TransactionSender};
 
+use ethers::types::H256;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio::time::{interval, Duration};
@@ -21,8 +25,23 @@ pub struct CoordinationEngine {
     state_manager: Arc<StateManager>,
     /// Dependency graph
     dependency_graph: Arc<DependencyGraph>,
+    /// Scored, capacity-bounded queue over the dependency graph's ready set
+    priority_queue: Arc<PriorityQueue>,
     /// Transaction sender
     tx_sender: Arc<TransactionSender>,
+    /// Nonce-ordered batch submitter, one instance per wallet, shared
+    /// across all chains
+    scheduler: Arc<Scheduler>,
+    /// Backstop for submitted transactions whose completion event was
+    /// missed by the live subscription
+    eventuality_tracker: Arc<EventualityTracker>,
+    /// Gas escalator for stuck transactions
+    gas_escalator: Arc<GasEscalator>,
+    /// Confirmation tracker for submitted transactions
+    confirmation_tracker: Arc<ConfirmationTracker>,
+    /// Advances confirmation depth for transactions observed executed but
+    /// not yet past the target chain's `confirmation_blocks`
+    confirmation_watcher: Arc<ConfirmationWatcher>,
     /// Configuration
     config: RelayerConfig,
     /// Shutdown flag
@@ -35,35 +54,99 @@ impl CoordinationEngine {
         chain_manager: Arc<ChainManager>,
         state_manager: Arc<StateManager>,
         config: RelayerConfig,
+        wallet_config: WalletConfig,
     ) -> RelayerResult<Self> {
-        let dependency_graph = Arc::new(DependencyGraph::new());
+        let dependency_graph = Arc::new(DependencyGraph::new(state_manager.clone()));
+        let priority_queue = Arc::new(PriorityQueue::new(dependency_graph.clone(), &config));
         let tx_sender = Arc::new(TransactionSender::new(
             chain_manager.clone(),
             state_manager.clone(),
             config.clone(),
+            &wallet_config,
         ).await?);
+        let scheduler = Arc::new(Scheduler::new(tx_sender.clone()));
+        let eventuality_tracker = Arc::new(EventualityTracker::new(chain_manager.clone()));
 
-        // Load pending transactions from database
-        let pending = state_manager.get_pending_transactions().await?;
-        for tx in pending {
-            dependency_graph.add_transaction(tx).await;
-        }
+        let gas_escalator = Arc::new(GasEscalator::new(
+            chain_manager.clone(),
+            tx_sender.nonce_manager().clone(),
+            tx_sender.clone(),
+            config.clone(),
+        ));
+        tx_sender.attach_escalator(gas_escalator.clone());
+
+        let confirmation_tracker = Arc::new(ConfirmationTracker::new(
+            chain_manager.clone(),
+            tx_sender.nonce_manager().clone(),
+            tx_sender.clone(),
+            state_manager.clone(),
+            config.clone(),
+        ));
+        tx_sender.attach_confirmation_tracker(confirmation_tracker.clone());
+
+        let confirmation_watcher = Arc::new(ConfirmationWatcher::new(
+            chain_manager.clone(),
+            dependency_graph.clone(),
+            config.clone(),
+        ));
+
+        // Rebuild dependency-graph state from the durable event log (a
+        // snapshot baseline plus every event since the last compaction)
+        // rather than a flat snapshot load, so recovery after a crash
+        // mid-transition is deterministic.
+        dependency_graph.replay().await?;
 
         Ok(Self {
             chain_manager,
             state_manager,
             dependency_graph,
+            priority_queue,
             tx_sender,
+            scheduler,
+            eventuality_tracker,
+            gas_escalator,
+            confirmation_tracker,
+            confirmation_watcher,
             config,
             shutdown: Arc::new(RwLock::new(false)),
         })
     }
 
+    /// Gas escalator for stuck transactions, spawned alongside the engine
+    pub fn gas_escalator(&self) -> Arc<GasEscalator> {
+        self.gas_escalator.clone()
+    }
+
+    /// Confirmation tracker for submitted transactions, spawned alongside the engine
+    pub fn confirmation_tracker(&self) -> Arc<ConfirmationTracker> {
+        self.confirmation_tracker.clone()
+    }
+
+    /// Confirmation-depth watcher for `Confirming` transactions, spawned
+    /// alongside the engine
+    pub fn confirmation_watcher(&self) -> Arc<ConfirmationWatcher> {
+        self.confirmation_watcher.clone()
+    }
+
+    /// Scored, capacity-bounded queue over the dependency graph's ready set,
+    /// surfaced to the API for `/stats`
+    pub fn priority_queue(&self) -> Arc<PriorityQueue> {
+        self.priority_queue.clone()
+    }
+
     /// Main coordination loop
     pub async fn run(&self) -> RelayerResult<()> {
+        use futures::StreamExt;
+
         // Subscribe to events from all chains
         let mut event_rx = self.chain_manager.subscribe_events();
 
+        // Pushed pending-transaction state changes (e.g. a dependency
+        // becoming Finalized), so we can react without waiting for the next
+        // process_interval tick. Best-effort: process_interval stays as the
+        // fallback poll for anything missed while this was reconnecting.
+        let mut state_change_rx = Box::pin(self.state_manager.subscribe_state_changes());
+
         // Processing interval
         let mut process_interval = interval(Duration::from_millis(self.config.poll_interval_ms));
 
@@ -85,6 +168,23 @@ impl CoordinationEngine {
                     }
                 }
 
+                // Pushed state changes: wake the ready-queue processor early
+                // instead of waiting out the rest of the poll interval
+                Some((tx_id, state)) = state_change_rx.next() => {
+                    debug!("Pushed state change for {}: {:?}", hex::encode(tx_id), state);
+
+                    // A reorg rewind reverts the persisted row to Buffered;
+                    // mirror that into the in-memory graph so it's taken out
+                    // of the ready set until its dependency is re-resolved.
+                    if state == TransactionState::Buffered {
+                        self.dependency_graph.mark_buffered(&tx_id).await;
+                    }
+
+                    if let Err(e) = self.process_pending().await {
+                        error!("Error processing pending transactions after push notification: {}", e);
+                    }
+                }
+
                 // Periodic processing of pending transactions
                 _ = process_interval.tick() => {
                     if let Err(e) = self.process_pending().await {
@@ -114,6 +214,7 @@ impl CoordinationEngine {
                 origin_rollup,
                 target_rollup,
                 timestamp,
+                block_number,
                 ..
             } => {
                 self.handle_transaction_buffered(
@@ -122,6 +223,7 @@ impl CoordinationEngine {
                     origin_rollup,
                     target_rollup,
                     timestamp,
+                    block_number,
                 )
                 .await?;
             }
@@ -139,12 +241,19 @@ impl CoordinationEngine {
                 self.handle_dependency_resolved(chain_id, tx_id, dependency_id).await?;
             }
 
-            ContractEvent::TransactionExecuted { tx_id, .. } => {
-                self.dependency_graph.mark_finalized(&tx_id).await;
+            ContractEvent::TransactionExecuted {
+                chain_id,
+                tx_id,
+                block_number,
+                tx_hash,
+            } => {
+                self.eventuality_tracker.resolve(&tx_id);
+                self.mark_finalized_with_provenance(chain_id, tx_id, tx_hash, block_number).await?;
                 info!("Transaction {:?} executed successfully", hex::encode(tx_id));
             }
 
             ContractEvent::TransactionFailed { tx_id, reason, .. } => {
+                self.eventuality_tracker.resolve(&tx_id);
                 self.dependency_graph.mark_failed(&tx_id).await;
                 warn!("Transaction {:?} failed: {}", hex::encode(tx_id), reason);
             }
@@ -158,6 +267,34 @@ impl CoordinationEngine {
                 self.handle_swap_fill(chain_id, order_id, fill_id).await?;
             }
 
+            ContractEvent::TransactionForced {
+                chain_id,
+                relayed_id,
+                payload,
+                ..
+            } => {
+                self.state_manager
+                    .record_forced_transaction(&relayed_id, chain_id, &payload)
+                    .await?;
+                info!(
+                    "Forced transaction {} ingested on chain {}",
+                    hex::encode(relayed_id),
+                    chain_id
+                );
+            }
+
+            ContractEvent::ForcedTransactionExecuted { relayed_id, .. } => {
+                self.state_manager.mark_forced_executed(&relayed_id).await?;
+                info!("Forced transaction {} executed", hex::encode(relayed_id));
+            }
+
+            ContractEvent::ForcedTransactionFailed {
+                relayed_id, reason, ..
+            } => {
+                self.state_manager.mark_forced_failed(&relayed_id, &reason).await?;
+                warn!("Forced transaction {} failed: {}", hex::encode(relayed_id), reason);
+            }
+
             ContractEvent::ContractPaused { chain_id, .. } => {
                 warn!("Contract paused on chain {}", chain_id);
                 crate::metrics::record_contract_paused(chain_id);
@@ -175,6 +312,19 @@ impl CoordinationEngine {
                 crate::metrics::record_circuit_breaker(chain_id);
             }
 
+            ContractEvent::ChainReorged { chain_id, common_ancestor } => {
+                let reverted = self.dependency_graph.handle_reorg(chain_id, common_ancestor).await;
+                if !reverted.is_empty() {
+                    warn!(
+                        "Chain {} reorg past block {}: reverted {} dependency-graph transaction(s) back out of Finalized/Ready: {:?}",
+                        chain_id,
+                        common_ancestor,
+                        reverted.len(),
+                        reverted.iter().map(hex::encode).collect::<Vec<_>>()
+                    );
+                }
+            }
+
             _ => {
                 // Other events logged but not actioned
                 debug!("Unhandled event type: {}", event.name());
@@ -184,6 +334,59 @@ impl CoordinationEngine {
         Ok(())
     }
 
+    /// Move a transaction into `Confirming`, first resolving the executing
+    /// block's hash so `DependencyGraph::handle_reorg` can later tell
+    /// whether that block is still canonical. Best-effort: if the block
+    /// hash can't be resolved (e.g. a transient RPC error), the transaction
+    /// still moves into `Confirming` rather than blocking on it, just
+    /// without reorg protection for this one observation. Real finalization
+    /// (and `notify_dependents`) is deferred to `ConfirmationWatcher`, which
+    /// finalizes once either the chain's configured `FinalityTracker`
+    /// backend or its raw `confirmation_blocks` depth clears the
+    /// transaction, whichever comes first.
+    async fn mark_finalized_with_provenance(
+        &self,
+        chain_id: u64,
+        tx_id: [u8; 32],
+        tx_hash: ethers::types::H256,
+        block_number: u64,
+    ) -> RelayerResult<()> {
+        let block_hash = match self.chain_manager.get_provider(chain_id) {
+            Ok(provider) => match provider.get_block(block_number).await {
+                Ok(Some(block)) => block.hash,
+                Ok(None) => None,
+                Err(e) => {
+                    warn!(
+                        "Failed to resolve block {} hash for chain {} while finalizing tx {}: {}",
+                        block_number, chain_id, hex::encode(tx_id), e
+                    );
+                    None
+                }
+            },
+            Err(e) => {
+                warn!("No provider for chain {} while finalizing tx {}: {}", chain_id, hex::encode(tx_id), e);
+                None
+            }
+        };
+
+        // `handle_reorg` keys only on (chain_id, block_number); the hash is
+        // kept for diagnostics and falls back to zero when it couldn't be
+        // resolved, which doesn't affect revert correctness. `tx_hash` is the
+        // real EVM transaction hash from the `TransactionExecuted` log (not
+        // `tx_id`, the contract's application-level identifier), so
+        // `FinalityTracker` has something `eth_getTransactionReceipt` can
+        // actually resolve.
+        let provenance = ExecutionProvenance {
+            chain_id,
+            block_number,
+            block_hash: block_hash.unwrap_or_else(ethers::types::H256::zero),
+            tx_hash,
+        };
+        self.dependency_graph.mark_confirming(&tx_id, provenance).await;
+
+        Ok(())
+    }
+
     /// Handle new buffered transaction
     async fn handle_transaction_buffered(
         &self,
@@ -192,6 +395,7 @@ impl CoordinationEngine {
         origin_rollup: ethers::types::Address,
         target_rollup: ethers::types::Address,
         timestamp: u64,
+        block_number: u64,
     ) -> RelayerResult<()> {
         info!(
             "New transaction buffered: {} on chain {}",
@@ -206,6 +410,8 @@ impl CoordinationEngine {
         let pending_tx = PendingTransaction {
             tx_id,
             origin_chain,
+            origin: origin_rollup,
+            origin_block: block_number,
             target_chain,
             dependency_id: None, // Will be set when we query the contract
             swap_group_id: None,
@@ -281,9 +487,21 @@ impl CoordinationEngine {
     async fn process_pending(&self) -> RelayerResult<()> {
         // Get all ready transactions
         for chain_id in self.chain_manager.connected_chains() {
-            let ready_txs = self.dependency_graph.get_ready_for_chain(chain_id).await;
+            crate::metrics::record_pending_nonces(
+                chain_id,
+                self.tx_sender.nonce_manager().pending_count(chain_id).await,
+            );
+
+            // Re-queue anything scheduled behind a nonce that was dropped
+            // from the mempool before this tick's batch goes out, so it
+            // doesn't get stuck waiting behind a transaction that will
+            // never confirm.
+            for tx in self.scheduler.recover_gaps(chain_id).await {
+                self.submit_one(chain_id, tx).await;
+            }
 
-            for tx in ready_txs {
+            let mut batch = Vec::new();
+            for tx in self.priority_queue.next_ready_for_chain(chain_id).await {
                 // Check if transaction is part of a swap group
                 if let Some(group_id) = tx.swap_group_id {
                     // Wait for all swap group members to be ready
@@ -291,33 +509,60 @@ impl CoordinationEngine {
                         continue;
                     }
                 }
+                batch.push(tx);
+            }
 
-                // Submit the transaction
-                match self.tx_sender.submit_resolve(&tx).await {
-                    Ok(tx_hash) => {
-                        self.dependency_graph.mark_submitted(&tx.tx_id).await;
-                        info!(
-                            "Submitted resolve for {} on chain {}: {:?}",
-                            hex::encode(tx.tx_id),
-                            chain_id,
-                            tx_hash
-                        );
-                    }
-                    Err(e) => {
-                        if e.is_retryable() {
-                            warn!("Retryable error submitting tx: {}", e);
-                        } else {
-                            error!("Failed to submit tx: {}", e);
-                            self.dependency_graph.mark_failed(&tx.tx_id).await;
-                        }
-                    }
-                }
+            for (tx, result) in self.scheduler.submit_batch(chain_id, batch).await {
+                self.handle_submission_result(chain_id, tx, result).await;
             }
         }
 
         Ok(())
     }
 
+    /// Submit a single transaction outside of the scheduler's batch
+    /// ordering (used for gap-recovery resubmissions, each of which gets a
+    /// fresh nonce of its own from `submit_resolve`).
+    async fn submit_one(&self, chain_id: u64, tx: PendingTransaction) {
+        let result = self.tx_sender.submit_resolve(&tx).await.map(|(_, hash)| hash);
+        self.handle_submission_result(chain_id, tx, result).await;
+    }
+
+    async fn handle_submission_result(
+        &self,
+        chain_id: u64,
+        tx: PendingTransaction,
+        result: RelayerResult<H256>,
+    ) {
+        match result {
+            Ok(tx_hash) => {
+                self.dependency_graph.mark_submitted(&tx.tx_id).await;
+
+                let submitted_at_block = match self.chain_manager.get_provider(chain_id) {
+                    Ok(provider) => provider.get_block_number().await.unwrap_or(0),
+                    Err(_) => 0,
+                };
+                self.eventuality_tracker
+                    .register(tx.tx_id, chain_id, submitted_at_block);
+
+                info!(
+                    "Submitted resolve for {} on chain {}: {:?}",
+                    hex::encode(tx.tx_id),
+                    chain_id,
+                    tx_hash
+                );
+            }
+            Err(e) => {
+                if e.is_retryable() {
+                    warn!("Retryable error submitting tx: {}", e);
+                } else {
+                    error!("Failed to submit tx: {}", e);
+                    self.dependency_graph.mark_failed(&tx.tx_id).await;
+                }
+            }
+        }
+    }
+
     /// Resolve target chain from rollup address
     fn resolve_target_chain(&self, _rollup: &ethers::types::Address) -> RelayerResult<u64> {
         // In production, maintain a mapping of rollup addresses to chain IDs
@@ -330,10 +575,46 @@ impl CoordinationEngine {
         // Clean up old transactions (24 hours)
         self.dependency_graph.cleanup(86400).await;
 
-        // Clean up finality tracker caches
+        // Compact the dependency event log: transactions whose latest event
+        // left them Finalized/Failed/Expired no longer need their history
+        // for replay
+        match self.state_manager.compact_dependency_events().await {
+            Ok(0) => {}
+            Ok(deleted) => debug!("Compacted {} dependency_events rows", deleted),
+            Err(e) => warn!("Failed to compact dependency_events: {}", e),
+        }
+
+        // Evict finality anchors abandoned for more than 24 hours
         for chain_id in self.chain_manager.connected_chains() {
             if let Ok(tracker) = self.chain_manager.get_finality_tracker(chain_id) {
-                tracker.cleanup_cache(10000).await;
+                if let Err(e) = tracker.evict_stale(86400).await {
+                    warn!("Failed to evict stale finality anchors for chain {}: {}", chain_id, e);
+                }
+            }
+        }
+
+        // Backstop for submitted transactions whose completion event the
+        // live subscription missed: actively check the target chain's logs
+        // for anything still outstanding past the poll-age threshold
+        let poll_age = self.config.eventuality_poll_age_secs as i64;
+        for (tx_id, chain_id, outcome) in self.eventuality_tracker.poll(poll_age).await {
+            match outcome {
+                Outcome::Executed { block_number, tx_hash } => {
+                    warn!(
+                        "Eventuality poll found missed TransactionExecuted for {} on chain {} (block {})",
+                        hex::encode(tx_id), chain_id, block_number
+                    );
+                    if let Err(e) = self.mark_finalized_with_provenance(chain_id, tx_id, tx_hash, block_number).await {
+                        warn!("Failed to finalize {} from polled eventuality: {}", hex::encode(tx_id), e);
+                    }
+                }
+                Outcome::Failed => {
+                    warn!(
+                        "Eventuality poll found missed TransactionFailed for {} on chain {}",
+                        hex::encode(tx_id), chain_id
+                    );
+                    self.dependency_graph.mark_failed(&tx_id).await;
+                }
             }
         }
     }
@@ -341,6 +622,9 @@ impl CoordinationEngine {
     /// Stop the coordination engine
     pub async fn stop(&self) {
         *self.shutdown.write().await = true;
+        self.gas_escalator.stop().await;
+        self.confirmation_tracker.stop().await;
+        self.confirmation_watcher.stop().await;
         info!("Coordination engine shutdown initiated");
     }
 }
@@ -0,0 +1,158 @@
+//! Eventuality-based completion polling
+//!
+//! `CoordinationEngine::handle_event` only learns a transaction completed by
+//! receiving a `TransactionExecuted`/`TransactionFailed` event on the live
+//! subscription; if that event is dropped (a reconnecting websocket, an RPC
+//! gap) the transaction is stuck in `Submitted` forever and everything
+//! depending on it deadlocks. Borrowing serai's Eventuality concept, every
+//! `mark_submitted` registers an expected on-chain outcome here, and
+//! `EventualityTracker::poll` actively checks for it past an age threshold
+//! by querying the target chain's logs directly - an independent path to
+//! the same `ContractEvent` the push subscription would have delivered.
+
+use crate::chain::ChainManager;
+use crate::error::RelayerResult;
+use crate::events::{ContractEvent, EventParser};
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use ethers::types::{Filter, H256};
+use std::sync::Arc;
+use tracing::warn;
+
+/// What we expect to eventually observe on `chain_id` for a submitted
+/// transaction
+#[derive(Debug, Clone, Copy)]
+struct Eventuality {
+    chain_id: u64,
+    /// Block `chain_id` was at when the resolve tx was submitted - logs are
+    /// only scanned from here forward
+    submitted_at_block: u64,
+    submitted_at: DateTime<Utc>,
+}
+
+/// What polling an `Eventuality` found
+pub enum Outcome {
+    Executed { block_number: u64, tx_hash: H256 },
+    Failed,
+}
+
+/// Tracks outstanding eventualities and polls the chain for their outcome
+pub struct EventualityTracker {
+    chain_manager: Arc<ChainManager>,
+    event_parsers: DashMap<u64, Arc<EventParser>>,
+    outstanding: DashMap<[u8; 32], Eventuality>,
+}
+
+impl EventualityTracker {
+    pub fn new(chain_manager: Arc<ChainManager>) -> Self {
+        Self {
+            chain_manager,
+            event_parsers: DashMap::new(),
+            outstanding: DashMap::new(),
+        }
+    }
+
+    /// Register a freshly submitted transaction's expected outcome
+    pub fn register(&self, tx_id: [u8; 32], chain_id: u64, submitted_at_block: u64) {
+        self.outstanding.insert(
+            tx_id,
+            Eventuality {
+                chain_id,
+                submitted_at_block,
+                submitted_at: Utc::now(),
+            },
+        );
+    }
+
+    /// Stop tracking a transaction whose outcome is already known (e.g. the
+    /// push subscription delivered it first)
+    pub fn resolve(&self, tx_id: &[u8; 32]) {
+        self.outstanding.remove(tx_id);
+    }
+
+    /// Check every eventuality at least `min_age_secs` old for its outcome,
+    /// clearing it from tracking once found. Yields `(tx_id, chain_id,
+    /// outcome)` so the caller can finalize without looking the chain back up.
+    pub async fn poll(&self, min_age_secs: i64) -> Vec<([u8; 32], u64, Outcome)> {
+        let due: Vec<([u8; 32], Eventuality)> = self
+            .outstanding
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .filter(|(_, e)| (Utc::now() - e.submitted_at).num_seconds() >= min_age_secs)
+            .collect();
+
+        let mut found = Vec::new();
+        for (tx_id, eventuality) in due {
+            match self.check(&tx_id, &eventuality).await {
+                Ok(Some(outcome)) => {
+                    self.outstanding.remove(&tx_id);
+                    found.push((tx_id, eventuality.chain_id, outcome));
+                }
+                Ok(None) => {}
+                Err(e) => warn!(
+                    "Eventuality poll failed for {} on chain {}: {}",
+                    hex::encode(tx_id),
+                    eventuality.chain_id,
+                    e
+                ),
+            }
+        }
+
+        found
+    }
+
+    async fn check(&self, tx_id: &[u8; 32], eventuality: &Eventuality) -> RelayerResult<Option<Outcome>> {
+        let provider = self.chain_manager.get_provider(eventuality.chain_id)?;
+        let parser = self.event_parser(eventuality.chain_id)?;
+
+        let topics: Vec<H256> = ["TransactionExecuted", "TransactionFailed"]
+            .into_iter()
+            .filter_map(|name| parser.topic_hash(name))
+            .collect();
+        if topics.is_empty() {
+            return Ok(None);
+        }
+
+        let filter = Filter::new()
+            .address(parser.contract_address())
+            .topic0(topics)
+            .topic1(H256::from(*tx_id))
+            .from_block(eventuality.submitted_at_block);
+
+        for log in provider.get_logs(&filter).await? {
+            let Ok(event) = parser.parse_log(&log) else {
+                continue;
+            };
+
+            match event {
+                ContractEvent::TransactionExecuted { tx_id: id, block_number, tx_hash, .. } if id == *tx_id => {
+                    return Ok(Some(Outcome::Executed { block_number, tx_hash }));
+                }
+                ContractEvent::TransactionFailed { tx_id: id, .. } if id == *tx_id => {
+                    return Ok(Some(Outcome::Failed));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Build (or reuse) the event parser for a chain
+    fn event_parser(&self, chain_id: u64) -> RelayerResult<Arc<EventParser>> {
+        if let Some(parser) = self.event_parsers.get(&chain_id) {
+            return Ok(parser.clone());
+        }
+
+        let provider = self.chain_manager.get_provider(chain_id)?;
+        let parser = Arc::new(EventParser::new(&provider.contract_address())?.with_chain_id(chain_id));
+        self.event_parsers.insert(chain_id, parser.clone());
+        Ok(parser)
+    }
+
+    /// Number of transactions still awaiting a polled outcome (for diagnostics)
+    pub fn outstanding_count(&self) -> usize {
+        self.outstanding.len()
+    }
+}
@@ -6,8 +6,14 @@
 //! 3. Submits resolve_dependency calls on target chains
 //! 4. Manages swap group atomicity
 
+pub mod confirmation_watcher;
 pub mod dependency;
 pub mod engine;
+pub mod eventuality;
+pub mod queue;
 
+pub use confirmation_watcher::ConfirmationWatcher;
 pub use dependency::{DependencyGraph, PendingTransaction, TransactionState};
 pub use engine::CoordinationEngine;
+pub use eventuality::EventualityTracker;
+pub use queue::{PriorityQueue, QueueStats, Scoring};
@@ -0,0 +1,162 @@
+//! Scored, capacity-bounded transaction queue
+//!
+//! `DependencyGraph` tracks transaction state but imposes no ordering or
+//! admission control: its ready set comes back in arbitrary map order, and
+//! nothing stops a single origin rollup from flooding the queue and
+//! starving everyone else. `PriorityQueue` sits on top of the graph and,
+//! for each chain, orders the ready set by a pluggable `Scoring`, caps how
+//! much of the queue a single origin may occupy, and evicts (marks
+//! `Expired`) the lowest-scored entries once a global capacity bound is hit.
+
+use super::dependency::{DependencyGraph, PendingTransaction, TransactionState};
+use crate::config::RelayerConfig;
+
+use dashmap::DashMap;
+use ethers::types::Address;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::debug;
+
+/// Orders ready transactions for submission. Higher score submits first.
+pub trait Scoring: Send + Sync {
+    fn score(&self, tx: &PendingTransaction, now: u64) -> i64;
+}
+
+/// Default scoring: oldest-arrival-first.
+///
+/// Every ready transaction on a chain is submitted at the same
+/// fee-history-derived gas price (see `GasEstimator`), so there's no
+/// per-transaction fee market to rank by the way a real mempool would. Age
+/// is the only priority signal available, and ranking by it directly serves
+/// the queue's goal: a flood of new arrivals can't push already-waiting
+/// transactions further back.
+pub struct ArrivalScoring;
+
+impl Scoring for ArrivalScoring {
+    fn score(&self, tx: &PendingTransaction, now: u64) -> i64 {
+        now.saturating_sub(tx.created_at) as i64
+    }
+}
+
+/// Depth and eviction counters for a single chain's queue (surfaced via `/stats`)
+#[derive(Debug, Clone, Default)]
+pub struct QueueStats {
+    pub ready: usize,
+    pub future: usize,
+    pub evicted_total: u64,
+}
+
+/// Scored, capacity-bounded view over `DependencyGraph`'s per-chain ready set
+pub struct PriorityQueue {
+    dependency_graph: Arc<DependencyGraph>,
+    scoring: Box<dyn Scoring>,
+    capacity: usize,
+    per_origin_cap: usize,
+    evicted: DashMap<u64, AtomicU64>,
+}
+
+impl PriorityQueue {
+    /// Create a queue using the default arrival-order scoring
+    pub fn new(dependency_graph: Arc<DependencyGraph>, config: &RelayerConfig) -> Self {
+        Self::with_scoring(dependency_graph, config, Box::new(ArrivalScoring))
+    }
+
+    /// Create a queue with a custom `Scoring` implementation
+    pub fn with_scoring(
+        dependency_graph: Arc<DependencyGraph>,
+        config: &RelayerConfig,
+        scoring: Box<dyn Scoring>,
+    ) -> Self {
+        let per_origin_cap = (config.queue_capacity * config.queue_per_origin_cap_percent as usize
+            / 100)
+            .max(1);
+
+        Self {
+            dependency_graph,
+            scoring,
+            capacity: config.queue_capacity,
+            per_origin_cap,
+            evicted: DashMap::new(),
+        }
+    }
+
+    /// Ready transactions for a chain, ordered by score (highest first),
+    /// with the per-origin cap and global capacity bound enforced. Entries
+    /// dropped by either bound are marked `Expired` in the graph so they
+    /// don't linger as phantom work.
+    pub async fn next_ready_for_chain(&self, chain_id: u64) -> Vec<PendingTransaction> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let mut ready = self.dependency_graph.get_ready_for_chain(chain_id).await;
+        ready.sort_by(|a, b| {
+            self.scoring
+                .score(b, now)
+                .cmp(&self.scoring.score(a, now))
+                .then_with(|| a.created_at.cmp(&b.created_at))
+        });
+
+        let mut per_origin_count: HashMap<Address, usize> = HashMap::new();
+        let mut admitted = Vec::with_capacity(ready.len());
+        let mut evicted = 0u64;
+
+        for tx in ready {
+            if admitted.len() >= self.capacity {
+                self.dependency_graph.mark_expired(&tx.tx_id).await;
+                evicted += 1;
+                continue;
+            }
+
+            let origin_count = per_origin_count.entry(tx.origin).or_insert(0);
+            if *origin_count >= self.per_origin_cap {
+                self.dependency_graph.mark_expired(&tx.tx_id).await;
+                evicted += 1;
+                continue;
+            }
+
+            *origin_count += 1;
+            admitted.push(tx);
+        }
+
+        if evicted > 0 {
+            self.evicted
+                .entry(chain_id)
+                .or_insert_with(|| AtomicU64::new(0))
+                .fetch_add(evicted, Ordering::Relaxed);
+            debug!(
+                "Chain {}: evicted {} queued transaction(s) (capacity/per-origin cap)",
+                chain_id, evicted
+            );
+            crate::metrics::record_queue_eviction(chain_id, evicted);
+        }
+
+        admitted
+    }
+
+    /// Depth and eviction stats for a chain, for `/stats`
+    pub async fn stats(&self, chain_id: u64) -> QueueStats {
+        let pending = self.dependency_graph.get_pending().await;
+        let (ready, future) = pending.iter().filter(|tx| tx.target_chain == chain_id).fold(
+            (0usize, 0usize),
+            |(ready, future), tx| match tx.state {
+                TransactionState::Ready => (ready + 1, future),
+                TransactionState::Submitted => (ready, future),
+                _ => (ready, future + 1),
+            },
+        );
+
+        QueueStats {
+            ready,
+            future,
+            evicted_total: self
+                .evicted
+                .get(&chain_id)
+                .map(|c| c.load(Ordering::Relaxed))
+                .unwrap_or(0),
+        }
+    }
+}
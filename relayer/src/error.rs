@@ -11,6 +11,9 @@ pub enum RelayerError {
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
 
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("Chain connection error for chain {chain_id}: {message}")]
     ChainConnection { chain_id: u64, message: String },
 
@@ -138,6 +138,35 @@ pub enum ContractEvent {
         tx_hash: H256,
     },
 
+    /// A forced (L1-relayed) transaction: a message submitted on the origin
+    /// chain that must be executed on the target chain regardless of
+    /// sequencer cooperation, identified by a deterministic `relayed_id`
+    /// rather than the usual `tx_id`
+    TransactionForced {
+        chain_id: u64,
+        relayed_id: [u8; 32],
+        payload: Vec<u8>,
+        block_number: u64,
+        tx_hash: H256,
+    },
+
+    /// A forced transaction executed successfully on the target chain
+    ForcedTransactionExecuted {
+        chain_id: u64,
+        relayed_id: [u8; 32],
+        block_number: u64,
+        tx_hash: H256,
+    },
+
+    /// A forced transaction's execution failed
+    ForcedTransactionFailed {
+        chain_id: u64,
+        relayed_id: [u8; 32],
+        reason: String,
+        block_number: u64,
+        tx_hash: H256,
+    },
+
     /// Unknown event
     Unknown {
         chain_id: u64,
@@ -145,6 +174,16 @@ pub enum ContractEvent {
         block_number: u64,
         tx_hash: H256,
     },
+
+    /// A confirmed chain reorg: every block above `common_ancestor` on
+    /// `chain_id` was retracted. Synthesized by
+    /// `ChainListener::detect_and_rewind_reorg` rather than decoded from a
+    /// contract log, so in-memory dependency-graph state can be unwound
+    /// alongside the already-reverted persisted rows.
+    ChainReorged {
+        chain_id: u64,
+        common_ancestor: u64,
+    },
 }
 
 impl ContractEvent {
@@ -165,7 +204,64 @@ impl ContractEvent {
             ContractEvent::ContractPaused { chain_id, .. } => *chain_id,
             ContractEvent::ContractUnpaused { chain_id, .. } => *chain_id,
             ContractEvent::CircuitBreakerTriggered { chain_id, .. } => *chain_id,
+            ContractEvent::TransactionForced { chain_id, .. } => *chain_id,
+            ContractEvent::ForcedTransactionExecuted { chain_id, .. } => *chain_id,
+            ContractEvent::ForcedTransactionFailed { chain_id, .. } => *chain_id,
             ContractEvent::Unknown { chain_id, .. } => *chain_id,
+            ContractEvent::ChainReorged { chain_id, .. } => *chain_id,
+        }
+    }
+
+    /// Get the block number this event was observed at
+    pub fn block_number(&self) -> u64 {
+        match self {
+            ContractEvent::TransactionBuffered { block_number, .. } => *block_number,
+            ContractEvent::DependencyResolved { block_number, .. } => *block_number,
+            ContractEvent::TransactionReady { block_number, .. } => *block_number,
+            ContractEvent::TransactionExecuted { block_number, .. } => *block_number,
+            ContractEvent::TransactionFailed { block_number, .. } => *block_number,
+            ContractEvent::TransactionExpired { block_number, .. } => *block_number,
+            ContractEvent::TransactionRefunded { block_number, .. } => *block_number,
+            ContractEvent::SwapGroupCreated { block_number, .. } => *block_number,
+            ContractEvent::SwapOrderCreated { block_number, .. } => *block_number,
+            ContractEvent::SwapFillCreated { block_number, .. } => *block_number,
+            ContractEvent::SwapCompleted { block_number, .. } => *block_number,
+            ContractEvent::ContractPaused { block_number, .. } => *block_number,
+            ContractEvent::ContractUnpaused { block_number, .. } => *block_number,
+            ContractEvent::CircuitBreakerTriggered { block_number, .. } => *block_number,
+            ContractEvent::TransactionForced { block_number, .. } => *block_number,
+            ContractEvent::ForcedTransactionExecuted { block_number, .. } => *block_number,
+            ContractEvent::ForcedTransactionFailed { block_number, .. } => *block_number,
+            ContractEvent::Unknown { block_number, .. } => *block_number,
+            ContractEvent::ChainReorged { common_ancestor, .. } => *common_ancestor,
+        }
+    }
+
+    /// Get the transaction hash this event was observed in
+    ///
+    /// `ChainReorged` isn't decoded from any single transaction, so this
+    /// returns the zero hash for it.
+    pub fn tx_hash(&self) -> H256 {
+        match self {
+            ContractEvent::TransactionBuffered { tx_hash, .. } => *tx_hash,
+            ContractEvent::DependencyResolved { tx_hash, .. } => *tx_hash,
+            ContractEvent::TransactionReady { tx_hash, .. } => *tx_hash,
+            ContractEvent::TransactionExecuted { tx_hash, .. } => *tx_hash,
+            ContractEvent::TransactionFailed { tx_hash, .. } => *tx_hash,
+            ContractEvent::TransactionExpired { tx_hash, .. } => *tx_hash,
+            ContractEvent::TransactionRefunded { tx_hash, .. } => *tx_hash,
+            ContractEvent::SwapGroupCreated { tx_hash, .. } => *tx_hash,
+            ContractEvent::SwapOrderCreated { tx_hash, .. } => *tx_hash,
+            ContractEvent::SwapFillCreated { tx_hash, .. } => *tx_hash,
+            ContractEvent::SwapCompleted { tx_hash, .. } => *tx_hash,
+            ContractEvent::ContractPaused { tx_hash, .. } => *tx_hash,
+            ContractEvent::ContractUnpaused { tx_hash, .. } => *tx_hash,
+            ContractEvent::CircuitBreakerTriggered { tx_hash, .. } => *tx_hash,
+            ContractEvent::TransactionForced { tx_hash, .. } => *tx_hash,
+            ContractEvent::ForcedTransactionExecuted { tx_hash, .. } => *tx_hash,
+            ContractEvent::ForcedTransactionFailed { tx_hash, .. } => *tx_hash,
+            ContractEvent::Unknown { tx_hash, .. } => *tx_hash,
+            ContractEvent::ChainReorged { .. } => H256::zero(),
         }
     }
 
@@ -186,7 +282,11 @@ impl ContractEvent {
             ContractEvent::ContractPaused { .. } => "contract_paused",
             ContractEvent::ContractUnpaused { .. } => "contract_unpaused",
             ContractEvent::CircuitBreakerTriggered { .. } => "circuit_breaker_triggered",
+            ContractEvent::TransactionForced { .. } => "transaction_forced",
+            ContractEvent::ForcedTransactionExecuted { .. } => "forced_transaction_executed",
+            ContractEvent::ForcedTransactionFailed { .. } => "forced_transaction_failed",
             ContractEvent::Unknown { .. } => "unknown",
+            ContractEvent::ChainReorged { .. } => "chain_reorged",
         }
     }
 
@@ -197,37 +297,51 @@ impl ContractEvent {
             ContractEvent::TransactionBuffered { .. }
                 | ContractEvent::TransactionReady { .. }
                 | ContractEvent::SwapFillCreated { .. }
+                | ContractEvent::TransactionForced { .. }
+                | ContractEvent::ForcedTransactionExecuted { .. }
+                | ContractEvent::ForcedTransactionFailed { .. }
+                | ContractEvent::ChainReorged { .. }
         )
     }
 }
 
-/// Event topic signatures (keccak256 of event signature)
-pub mod topics {
-    use ethers::types::H256;
-    use lazy_static::lazy_static;
-
-    lazy_static! {
-        // TesseractBuffer events
-        pub static ref TRANSACTION_BUFFERED: H256 =
-            "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
-                .parse()
-                .unwrap();
-        pub static ref DEPENDENCY_RESOLVED: H256 =
-            "0x2234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
-                .parse()
-                .unwrap();
-        pub static ref TRANSACTION_READY: H256 =
-            "0x3234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
-                .parse()
-                .unwrap();
-        // Add more as needed
-    }
+/// Human-readable ABI fragments for the events we act on.
+///
+/// Kept inline (rather than loaded from a build artifact) since the relayer
+/// only needs event signatures and decoding, not the full contract ABIs.
+mod abi_source {
+    /// Events emitted by the TesseractBuffer contract
+    pub const TESSERACT_BUFFER: &[&str] = &[
+        "event TransactionBuffered(bytes32 indexed txId, address indexed originRollup, address indexed targetRollup, uint256 timestamp)",
+        "event DependencyResolved(bytes32 indexed txId, bytes32 indexed dependencyId)",
+        "event TransactionReady(bytes32 indexed txId)",
+        "event TransactionExecuted(bytes32 indexed txId)",
+        "event TransactionFailed(bytes32 indexed txId, string reason)",
+        "event TransactionExpired(bytes32 indexed txId)",
+        "event TransactionRefunded(bytes32 indexed txId, address indexed recipient)",
+        "event SwapGroupCreated(bytes32 indexed swapGroupId)",
+        "event ContractPaused()",
+        "event ContractUnpaused()",
+        "event CircuitBreakerTriggered(uint256 failureCount)",
+        "event TransactionForced(bytes32 indexed relayedId, bytes payload)",
+        "event ForcedTransactionExecuted(bytes32 indexed relayedId)",
+        "event ForcedTransactionFailed(bytes32 indexed relayedId, string reason)",
+    ];
+
+    /// Events emitted by the AtomicSwapCoordinator contract
+    pub const ATOMIC_SWAP_COORDINATOR: &[&str] = &[
+        "event SwapOrderCreated(bytes32 indexed orderId, address indexed maker, address offerChain, address wantChain, uint256 offerAmount, uint256 wantAmount, uint256 deadline)",
+        "event SwapFillCreated(bytes32 indexed orderId, bytes32 indexed fillId, address indexed taker, uint256 offerAmountFilled, uint256 wantAmountFilled)",
+        "event SwapCompleted(bytes32 indexed orderId)",
+    ];
 }
 
 /// Event parser for TesseractBuffer and AtomicSwapCoordinator contracts
 pub struct EventParser {
     chain_id: u64,
     contract_address: Address,
+    /// Event topic hash (keccak256 of the event signature) -> decoded ABI event
+    event_signatures: std::collections::HashMap<H256, Event>,
 }
 
 impl EventParser {
@@ -236,10 +350,13 @@ impl EventParser {
         let address = Address::from_str(contract_address)
             .map_err(|e| RelayerError::Config(format!("Invalid address: {}", e)))?;
 
+        let event_signatures = build_event_signatures()?;
+
         // Chain ID will be set when parsing
         Ok(Self {
             chain_id: 0,
             contract_address: address,
+            event_signatures,
         })
     }
 
@@ -249,58 +366,249 @@ impl EventParser {
         self
     }
 
+    /// Topic hash (keccak256 of the event signature) for a named event,
+    /// for building a `Filter` to poll for it directly rather than via the
+    /// block subscription (see `coordination::eventuality`).
+    pub fn topic_hash(&self, event_name: &str) -> Option<H256> {
+        self.event_signatures
+            .iter()
+            .find(|(_, event)| event.name == event_name)
+            .map(|(topic, _)| *topic)
+    }
+
+    /// Address this parser expects logs to come from
+    pub fn contract_address(&self) -> Address {
+        self.contract_address
+    }
+
     /// Parse a log entry into a ContractEvent
     pub fn parse_log(&self, log: &Log) -> RelayerResult<ContractEvent> {
         let block_number = log.block_number.map(|b| b.as_u64()).unwrap_or(0);
         let tx_hash = log.transaction_hash.unwrap_or_default();
-
-        // Get primary topic
         let topic = log.topics.first().copied().unwrap_or_default();
 
-        // Parse based on topic signature
-        // In production, we'd match against actual event signatures
-        // For now, return Unknown for unrecognized events
-        Ok(ContractEvent::Unknown {
-            chain_id: self.chain_id,
-            topic,
-            block_number,
-            tx_hash,
-        })
-    }
-
-    /// Parse TransactionBuffered event data
-    fn parse_transaction_buffered(&self, log: &Log) -> RelayerResult<ContractEvent> {
-        let block_number = log.block_number.map(|b| b.as_u64()).unwrap_or(0);
-        let tx_hash = log.transaction_hash.unwrap_or_default();
+        let Some(event) = self.event_signatures.get(&topic) else {
+            return Ok(ContractEvent::Unknown {
+                chain_id: self.chain_id,
+                topic,
+                block_number,
+                tx_hash,
+            });
+        };
 
-        // Parse indexed parameters from topics
-        let tx_id: [u8; 32] = log.topics.get(1)
-            .map(|t| t.0)
-            .unwrap_or_default();
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        };
 
-        let origin_rollup = log.topics.get(2)
-            .map(|t| Address::from_slice(&t.0[12..32]))
-            .unwrap_or_default();
+        let decoded = event
+            .parse_log(raw_log)
+            .map_err(|e| RelayerError::EventParsing(format!("{}: {}", event.name, e)))?;
 
-        let target_rollup = log.topics.get(3)
-            .map(|t| Address::from_slice(&t.0[12..32]))
-            .unwrap_or_default();
+        self.to_contract_event(&event.name, &decoded, block_number, tx_hash, topic)
+    }
 
-        // Parse non-indexed parameters from data
-        let timestamp = if log.data.len() >= 32 {
-            U256::from_big_endian(&log.data[0..32]).as_u64()
-        } else {
-            0
+    /// Map a decoded ABI log to the matching `ContractEvent` variant
+    fn to_contract_event(
+        &self,
+        event_name: &str,
+        log: &ethers::abi::Log,
+        block_number: u64,
+        tx_hash: H256,
+        topic: H256,
+    ) -> RelayerResult<ContractEvent> {
+        let chain_id = self.chain_id;
+
+        let event = match event_name {
+            "TransactionBuffered" => ContractEvent::TransactionBuffered {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                origin_rollup: address(log, "originRollup")?,
+                target_rollup: address(log, "targetRollup")?,
+                timestamp: uint(log, "timestamp")?.as_u64(),
+                block_number,
+                tx_hash,
+            },
+            "DependencyResolved" => ContractEvent::DependencyResolved {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                dependency_id: fixed_bytes32(log, "dependencyId")?,
+                block_number,
+                tx_hash,
+            },
+            "TransactionReady" => ContractEvent::TransactionReady {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                block_number,
+                tx_hash,
+            },
+            "TransactionExecuted" => ContractEvent::TransactionExecuted {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                block_number,
+                tx_hash,
+            },
+            "TransactionFailed" => ContractEvent::TransactionFailed {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                reason: string(log, "reason")?,
+                block_number,
+                tx_hash,
+            },
+            "TransactionExpired" => ContractEvent::TransactionExpired {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                block_number,
+                tx_hash,
+            },
+            "TransactionRefunded" => ContractEvent::TransactionRefunded {
+                chain_id,
+                tx_id: fixed_bytes32(log, "txId")?,
+                recipient: address(log, "recipient")?,
+                block_number,
+                tx_hash,
+            },
+            "SwapGroupCreated" => ContractEvent::SwapGroupCreated {
+                chain_id,
+                swap_group_id: fixed_bytes32(log, "swapGroupId")?,
+                block_number,
+                tx_hash,
+            },
+            "SwapOrderCreated" => ContractEvent::SwapOrderCreated {
+                chain_id,
+                order_id: fixed_bytes32(log, "orderId")?,
+                maker: address(log, "maker")?,
+                offer_chain: address(log, "offerChain")?,
+                want_chain: address(log, "wantChain")?,
+                offer_amount: uint(log, "offerAmount")?,
+                want_amount: uint(log, "wantAmount")?,
+                deadline: uint(log, "deadline")?.as_u64(),
+                block_number,
+                tx_hash,
+            },
+            "SwapFillCreated" => ContractEvent::SwapFillCreated {
+                chain_id,
+                order_id: fixed_bytes32(log, "orderId")?,
+                fill_id: fixed_bytes32(log, "fillId")?,
+                taker: address(log, "taker")?,
+                offer_amount_filled: uint(log, "offerAmountFilled")?,
+                want_amount_filled: uint(log, "wantAmountFilled")?,
+                block_number,
+                tx_hash,
+            },
+            "SwapCompleted" => ContractEvent::SwapCompleted {
+                chain_id,
+                order_id: fixed_bytes32(log, "orderId")?,
+                block_number,
+                tx_hash,
+            },
+            "ContractPaused" => ContractEvent::ContractPaused {
+                chain_id,
+                block_number,
+                tx_hash,
+            },
+            "ContractUnpaused" => ContractEvent::ContractUnpaused {
+                chain_id,
+                block_number,
+                tx_hash,
+            },
+            "CircuitBreakerTriggered" => ContractEvent::CircuitBreakerTriggered {
+                chain_id,
+                failure_count: uint(log, "failureCount")?.as_u64(),
+                block_number,
+                tx_hash,
+            },
+            "TransactionForced" => ContractEvent::TransactionForced {
+                chain_id,
+                relayed_id: fixed_bytes32(log, "relayedId")?,
+                payload: bytes(log, "payload")?,
+                block_number,
+                tx_hash,
+            },
+            "ForcedTransactionExecuted" => ContractEvent::ForcedTransactionExecuted {
+                chain_id,
+                relayed_id: fixed_bytes32(log, "relayedId")?,
+                block_number,
+                tx_hash,
+            },
+            "ForcedTransactionFailed" => ContractEvent::ForcedTransactionFailed {
+                chain_id,
+                relayed_id: fixed_bytes32(log, "relayedId")?,
+                reason: string(log, "reason")?,
+                block_number,
+                tx_hash,
+            },
+            _ => ContractEvent::Unknown {
+                chain_id,
+                topic,
+                block_number,
+                tx_hash,
+            },
         };
 
-        Ok(ContractEvent::TransactionBuffered {
-            chain_id: self.chain_id,
-            tx_id,
-            origin_rollup,
-            target_rollup,
-            timestamp,
-            block_number,
-            tx_hash,
-        })
+        Ok(event)
+    }
+}
+
+/// Build the topic-hash -> event map for both contracts' ABIs
+fn build_event_signatures() -> RelayerResult<std::collections::HashMap<H256, Event>> {
+    let mut signatures = std::collections::HashMap::new();
+
+    for source in [abi_source::TESSERACT_BUFFER, abi_source::ATOMIC_SWAP_COORDINATOR] {
+        let abi: Abi = ethers::abi::parse_abi(source)
+            .map_err(|e| RelayerError::EventParsing(format!("failed to parse ABI: {}", e)))?;
+
+        for event in abi.events() {
+            signatures.insert(event.signature(), event.clone());
+        }
     }
+
+    Ok(signatures)
+}
+
+/// Look up a named parameter from a decoded ABI log
+fn param<'a>(log: &'a ethers::abi::Log, name: &str) -> RelayerResult<&'a ethers::abi::Token> {
+    log.params
+        .iter()
+        .find(|p| p.name == name)
+        .map(|p| &p.value)
+        .ok_or_else(|| RelayerError::EventParsing(format!("missing field `{}`", name)))
+}
+
+fn fixed_bytes32(log: &ethers::abi::Log, name: &str) -> RelayerResult<[u8; 32]> {
+    let bytes = param(log, name)?
+        .clone()
+        .into_fixed_bytes()
+        .ok_or_else(|| RelayerError::EventParsing(format!("field `{}` is not bytes32", name)))?;
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&bytes);
+    Ok(out)
+}
+
+fn address(log: &ethers::abi::Log, name: &str) -> RelayerResult<Address> {
+    param(log, name)?
+        .clone()
+        .into_address()
+        .ok_or_else(|| RelayerError::EventParsing(format!("field `{}` is not an address", name)))
+}
+
+fn uint(log: &ethers::abi::Log, name: &str) -> RelayerResult<U256> {
+    param(log, name)?
+        .clone()
+        .into_uint()
+        .ok_or_else(|| RelayerError::EventParsing(format!("field `{}` is not a uint", name)))
+}
+
+fn string(log: &ethers::abi::Log, name: &str) -> RelayerResult<String> {
+    param(log, name)?
+        .clone()
+        .into_string()
+        .ok_or_else(|| RelayerError::EventParsing(format!("field `{}` is not a string", name)))
+}
+
+fn bytes(log: &ethers::abi::Log, name: &str) -> RelayerResult<Vec<u8>> {
+    param(log, name)?
+        .clone()
+        .into_bytes()
+        .ok_or_else(|| RelayerError::EventParsing(format!("field `{}` is not bytes", name)))
 }
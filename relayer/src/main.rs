@@ -64,6 +64,7 @@ async fn main() -> Result<()> {
             chain_manager.clone(),
             state_manager.clone(),
             settings.relayer.clone(),
+            settings.wallet.clone(),
         )
         .await?,
     );
@@ -74,8 +75,11 @@ async fn main() -> Result<()> {
         let settings = settings.clone();
         let state_manager = state_manager.clone();
         let chain_manager = chain_manager.clone();
+        let priority_queue = coordination_engine.priority_queue();
         async move {
-            if let Err(e) = api::run_server(settings.api, state_manager, chain_manager).await {
+            if let Err(e) =
+                api::run_server(settings.api, state_manager, chain_manager, priority_queue).await
+            {
                 error!("API server error: {}", e);
             }
         }
@@ -112,6 +116,31 @@ async fn main() -> Result<()> {
         }
     });
 
+    // Start gas escalator for stuck transactions
+    let escalator_handle = tokio::spawn({
+        let escalator = coordination_engine.gas_escalator();
+        async move {
+            escalator.run().await;
+        }
+    });
+
+    // Start confirmation tracker for submitted transactions
+    let confirmation_handle = tokio::spawn({
+        let tracker = coordination_engine.confirmation_tracker();
+        async move {
+            tracker.run().await;
+        }
+    });
+
+    // Start confirmation-depth watcher, advancing `Confirming` transactions
+    // toward `Finalized`
+    let confirmation_watch_handle = tokio::spawn({
+        let watcher = coordination_engine.confirmation_watcher();
+        async move {
+            watcher.run().await;
+        }
+    });
+
     // Health check loop
     let health_handle = tokio::spawn({
         let chain_manager = chain_manager.clone();
@@ -139,6 +168,12 @@ async fn main() -> Result<()> {
         }
     });
 
+    // Reload chain configuration on SIGHUP without restarting the process
+    let reload_handle = tokio::spawn({
+        let chain_manager = chain_manager.clone();
+        async move { reload_on_sighup(chain_manager).await }
+    });
+
     info!("Tesseract Relayer is running");
     info!("API server: http://{}:{}", settings.api.host, settings.api.port);
     if settings.metrics.enabled {
@@ -158,7 +193,11 @@ async fn main() -> Result<()> {
     api_handle.abort();
     listener_handle.abort();
     coordination_handle.abort();
+    escalator_handle.abort();
+    confirmation_handle.abort();
+    confirmation_watch_handle.abort();
     health_handle.abort();
+    reload_handle.abort();
     if let Some(h) = metrics_handle {
         h.abort();
     }
@@ -203,3 +242,38 @@ async fn shutdown_signal() {
         _ = terminate => {},
     }
 }
+
+/// Waits for SIGHUP and, on each one, re-reads and validates the config file
+/// and hot-reconciles `ChainManager` against it. A bad reload (unparsable
+/// file, failed validation) is logged and the relayer keeps running on its
+/// last-known-good config rather than crashing or partially applying it.
+#[cfg(unix)]
+async fn reload_on_sighup(chain_manager: Arc<ChainManager>) {
+    let mut sighup = match signal::unix::signal(signal::unix::SignalKind::hangup()) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Failed to install SIGHUP handler: {}", e);
+            return;
+        }
+    };
+
+    loop {
+        sighup.recv().await;
+        info!("SIGHUP received, reloading configuration");
+
+        match Settings::load() {
+            Ok(settings) => {
+                chain_manager.reconcile(&settings).await;
+                info!("Configuration reload complete");
+            }
+            Err(e) => {
+                error!("Configuration reload failed, keeping previous config: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn reload_on_sighup(_chain_manager: Arc<ChainManager>) {
+    std::future::pending::<()>().await
+}
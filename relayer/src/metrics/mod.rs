@@ -91,6 +91,96 @@ lazy_static! {
         &["chain_id"]
     ).unwrap();
 
+    pub static ref GAS_ESCALATIONS: CounterVec = register_counter_vec!(
+        "tesseract_gas_escalations_total",
+        "Total gas escalation steps applied to stuck transactions",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref GAS_ESCALATION_CEILING: CounterVec = register_counter_vec!(
+        "tesseract_gas_escalation_ceiling_total",
+        "Total times the gas escalator hit its attempt, multiplier, or max_gas_price_gwei ceiling",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref TX_DROPPED_REPLACED: CounterVec = register_counter_vec!(
+        "tesseract_transactions_dropped_replaced_total",
+        "Total tracked submissions superseded by a replacement before confirming",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref TX_REORGED_OUT: CounterVec = register_counter_vec!(
+        "tesseract_transactions_reorged_out_total",
+        "Total confirmed claims invalidated by a reorg and resubmitted",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref REORGS_DETECTED: CounterVec = register_counter_vec!(
+        "tesseract_reorgs_detected_total",
+        "Total chain reorgs detected by the block listener",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref REORG_DEPTH: GaugeVec = register_gauge_vec!(
+        "tesseract_reorg_depth_blocks",
+        "Depth of the most recently detected reorg, in blocks",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref DEPENDENCY_STATE_REVERTED: CounterVec = register_counter_vec!(
+        "tesseract_dependency_state_reverted_total",
+        "Total dependency-graph transactions reverted out of Finalized/Ready by a reorg",
+        &["chain_id"]
+    ).unwrap();
+
+    // Finality-tracker metrics. Distinct from the `ChainListener` reorg
+    // metrics above: these cover reorgs caught by `FinalityTracker`'s own
+    // block-hash anchoring, underneath a transaction it's still waiting to
+    // finalize, rather than reorgs caught by the listener's block buffer.
+    pub static ref FINALITY_REORGS_TOTAL: CounterVec = register_counter_vec!(
+        "tesseract_reorgs_total",
+        "Total reorgs detected by finality block-hash anchoring",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref FINALITY_REORG_DEPTH: HistogramVec = register_histogram_vec!(
+        "tesseract_reorg_depth",
+        "Depth of reorgs detected by finality block-hash anchoring, in blocks",
+        &["chain_id"],
+        vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0]
+    ).unwrap();
+
+    pub static ref FINALITY_LATENCY: HistogramVec = register_histogram_vec!(
+        "tesseract_finality_latency_seconds",
+        "Time from when a transaction started finality tracking to when it finalized",
+        &["chain_id"],
+        vec![1.0, 5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0]
+    ).unwrap();
+
+    pub static ref FINALITY_PENDING: GaugeVec = register_gauge_vec!(
+        "tesseract_finality_pending",
+        "Transactions currently awaiting finality per chain",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref QUEUE_EVICTIONS: CounterVec = register_counter_vec!(
+        "tesseract_queue_evictions_total",
+        "Total queued transactions evicted by the capacity or per-origin cap",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref PENDING_NONCES: GaugeVec = register_gauge_vec!(
+        "tesseract_pending_nonces",
+        "Number of nonces currently awaiting confirmation per chain",
+        &["chain_id"]
+    ).unwrap();
+
+    pub static ref NONCE_GAPS_RECOVERED: CounterVec = register_counter_vec!(
+        "tesseract_nonce_gaps_recovered_total",
+        "Scheduled transactions re-queued after the nonce ahead of them was dropped from the mempool",
+        &["chain_id"]
+    ).unwrap();
+
     // Wallet metrics
     pub static ref WALLET_BALANCE: GaugeVec = register_gauge_vec!(
         "tesseract_wallet_balance_eth",
@@ -135,7 +225,9 @@ impl MetricsServer {
     }
 }
 
-async fn metrics_handler() -> String {
+/// Render the global Prometheus registry as text. Shared by the dedicated
+/// `MetricsServer` and the `/metrics` route on the main API router.
+pub async fn metrics_handler() -> String {
     let encoder = TextEncoder::new();
     let metric_families = prometheus::gather();
     let mut buffer = Vec::new();
@@ -217,6 +309,84 @@ pub fn record_circuit_breaker(chain_id: u64) {
         .inc();
 }
 
+pub fn record_gas_escalation(chain_id: u64) {
+    GAS_ESCALATIONS
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
+pub fn record_gas_escalation_ceiling(chain_id: u64) {
+    GAS_ESCALATION_CEILING
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
+pub fn record_tx_dropped_replaced(chain_id: u64) {
+    TX_DROPPED_REPLACED
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
+pub fn record_tx_reorged_out(chain_id: u64) {
+    TX_REORGED_OUT
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
+pub fn record_reorg(chain_id: u64, depth_blocks: u64) {
+    REORGS_DETECTED
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+    REORG_DEPTH
+        .with_label_values(&[&chain_id.to_string()])
+        .set(depth_blocks as f64);
+}
+
+pub fn record_dependency_state_reverted(chain_id: u64) {
+    DEPENDENCY_STATE_REVERTED
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
+pub fn record_finality_reorg(chain_id: u64, depth_blocks: u64) {
+    FINALITY_REORGS_TOTAL
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+    FINALITY_REORG_DEPTH
+        .with_label_values(&[&chain_id.to_string()])
+        .observe(depth_blocks as f64);
+}
+
+pub fn record_finality_latency(chain_id: u64, latency_secs: f64) {
+    FINALITY_LATENCY
+        .with_label_values(&[&chain_id.to_string()])
+        .observe(latency_secs);
+}
+
+pub fn record_finality_pending(chain_id: u64, count: usize) {
+    FINALITY_PENDING
+        .with_label_values(&[&chain_id.to_string()])
+        .set(count as f64);
+}
+
+pub fn record_queue_eviction(chain_id: u64, count: u64) {
+    QUEUE_EVICTIONS
+        .with_label_values(&[&chain_id.to_string()])
+        .inc_by(count as f64);
+}
+
+pub fn record_pending_nonces(chain_id: u64, count: usize) {
+    PENDING_NONCES
+        .with_label_values(&[&chain_id.to_string()])
+        .set(count as f64);
+}
+
+pub fn record_nonce_gap_recovered(chain_id: u64) {
+    NONCE_GAPS_RECOVERED
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
 pub fn record_wallet_balance(chain_id: u64, balance_eth: f64) {
     WALLET_BALANCE
         .with_label_values(&[&chain_id.to_string()])
@@ -8,4 +8,6 @@
 
 mod manager;
 
-pub use manager::StateManager;
+pub use manager::{
+    FinalityAnchor, ImportSummary, JobStatus, RelayedStatus, StateManager, SubmissionJob,
+};
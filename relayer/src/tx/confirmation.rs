@@ -0,0 +1,310 @@
+//! Reorg-aware confirmation tracking for submitted transactions
+//!
+//! `submit_resolve` only tells us a transaction was broadcast - it doesn't
+//! tell us the contract actually executed `resolve_dependency` for the
+//! expected `tx_id`, or that the block it landed in stays canonical. This
+//! module follows a submission through to a *claim*: the decoded
+//! `DependencyResolved`/`TransactionReady` event plus the block hash it was
+//! observed in. Only once the claim has survived `confirmation_blocks`
+//! confirmations and the block hash is still canonical is the `tx_id` marked
+//! resolved in `StateManager`. If the submission is superseded before it
+//! confirms (e.g. a gas escalation bump), or the claimed block is reorged
+//! out, the tracker emits a distinct metric for each case and, for a reorg,
+//! re-syncs the nonce and resubmits.
+
+use super::nonce::NonceManager;
+use super::sender::TransactionSender;
+use crate::chain::ChainManager;
+use crate::config::RelayerConfig;
+use crate::error::RelayerResult;
+use crate::events::{ContractEvent, EventParser};
+use crate::state::StateManager;
+
+use dashmap::DashMap;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::H256;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// A confirmed claim: the expected event plus the block it landed in, so we
+/// can tell when that block stops being canonical
+struct Claim {
+    block_hash: H256,
+    block_number: u64,
+}
+
+/// A submission being followed through to a resolved claim
+struct Submission {
+    tx_id: [u8; 32],
+    nonce: u64,
+    original_tx: TypedTransaction,
+    claim: Option<Claim>,
+}
+
+/// Tracks submitted transactions through to a reorg-safe resolution
+pub struct ConfirmationTracker {
+    chain_manager: Arc<ChainManager>,
+    nonce_manager: Arc<NonceManager>,
+    tx_sender: Arc<TransactionSender>,
+    state_manager: Arc<StateManager>,
+    config: RelayerConfig,
+    event_parsers: DashMap<u64, Arc<EventParser>>,
+    tracked: DashMap<(u64, H256), Submission>,
+    shutdown: Arc<RwLock<bool>>,
+}
+
+impl ConfirmationTracker {
+    /// Create a new confirmation tracker
+    pub fn new(
+        chain_manager: Arc<ChainManager>,
+        nonce_manager: Arc<NonceManager>,
+        tx_sender: Arc<TransactionSender>,
+        state_manager: Arc<StateManager>,
+        config: RelayerConfig,
+    ) -> Self {
+        Self {
+            chain_manager,
+            nonce_manager,
+            tx_sender,
+            state_manager,
+            config,
+            event_parsers: DashMap::new(),
+            tracked: DashMap::new(),
+            shutdown: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    /// Start following a freshly submitted transaction through to resolution
+    pub fn track(
+        &self,
+        chain_id: u64,
+        nonce: u64,
+        tx_hash: H256,
+        tx_id: [u8; 32],
+        original_tx: TypedTransaction,
+    ) {
+        self.tracked.insert(
+            (chain_id, tx_hash),
+            Submission {
+                tx_id,
+                nonce,
+                original_tx,
+                claim: None,
+            },
+        );
+    }
+
+    /// Run the confirmation loop until `stop()` is called
+    pub async fn run(&self) {
+        let poll_interval =
+            std::time::Duration::from_secs(self.config.confirmation_poll_interval_secs.max(1));
+        let mut ticker = tokio::time::interval(poll_interval);
+
+        loop {
+            if *self.shutdown.read().await {
+                break;
+            }
+            ticker.tick().await;
+            self.tick().await;
+        }
+    }
+
+    /// Stop the confirmation loop
+    pub async fn stop(&self) {
+        *self.shutdown.write().await = true;
+    }
+
+    async fn tick(&self) {
+        let keys: Vec<(u64, H256)> = self.tracked.iter().map(|e| *e.key()).collect();
+
+        for (chain_id, tx_hash) in keys {
+            if let Err(e) = self.check_one(chain_id, tx_hash).await {
+                warn!(
+                    "Confirmation check failed for chain {} tx {:?}: {}",
+                    chain_id, tx_hash, e
+                );
+            }
+        }
+
+        // Reap durable submission jobs abandoned by a crashed worker (this
+        // instance or another one sharing the same queue) so they become
+        // claimable again rather than sitting stuck as `running` forever.
+        match self
+            .state_manager
+            .requeue_stale_submissions(self.config.submission_job_heartbeat_timeout_secs as i64)
+            .await
+        {
+            Ok(0) => {}
+            Ok(requeued) => info!("Requeued {} stale submission job(s)", requeued),
+            Err(e) => warn!("Failed to reap stale submission jobs: {}", e),
+        }
+    }
+
+    async fn check_one(&self, chain_id: u64, tx_hash: H256) -> RelayerResult<()> {
+        // Superseded by a replacement (gas escalation, manual speed-up, ...)
+        // before we ever saw a receipt for this exact hash.
+        let nonce = match self.tracked.get(&(chain_id, tx_hash)) {
+            Some(entry) if entry.claim.is_none() => entry.nonce,
+            Some(_) => {
+                self.check_claim(chain_id, tx_hash).await?;
+                return Ok(());
+            }
+            None => return Ok(()),
+        };
+
+        let current_hash = self.nonce_manager.pending_tx_hash(chain_id, nonce).await;
+        let expected_hash = format!("{:?}", tx_hash);
+        if current_hash.as_deref() != Some(expected_hash.as_str()) {
+            debug!(
+                "Chain {} nonce {} superseded before tx {:?} confirmed",
+                chain_id, nonce, tx_hash
+            );
+            self.tracked.remove(&(chain_id, tx_hash));
+            crate::metrics::record_tx_dropped_replaced(chain_id);
+            return Ok(());
+        }
+
+        let provider = self.chain_manager.get_provider(chain_id)?;
+        let Some(receipt) = provider.get_transaction_receipt(tx_hash).await? else {
+            return Ok(());
+        };
+        let (Some(block_number), Some(block_hash)) = (receipt.block_number, receipt.block_hash)
+        else {
+            return Ok(());
+        };
+
+        let tx_id = match self.tracked.get(&(chain_id, tx_hash)) {
+            Some(entry) => entry.tx_id,
+            None => return Ok(()),
+        };
+
+        let parser = self.event_parser(chain_id)?;
+        let has_expected_event = receipt
+            .logs
+            .iter()
+            .filter_map(|log| parser.parse_log(log).ok())
+            .any(|event| event_resolves(&event, &tx_id));
+
+        if !has_expected_event {
+            debug!(
+                "Chain {} tx {:?} mined but expected event not yet observed",
+                chain_id, tx_hash
+            );
+            return Ok(());
+        }
+
+        if let Some(mut entry) = self.tracked.get_mut(&(chain_id, tx_hash)) {
+            entry.claim = Some(Claim {
+                block_hash,
+                block_number: block_number.as_u64(),
+            });
+        }
+
+        info!(
+            "Chain {} tx {:?} claimed at block {} ({:?})",
+            chain_id, tx_hash, block_number, block_hash
+        );
+
+        self.check_claim(chain_id, tx_hash).await
+    }
+
+    /// Re-check a claim that has already observed the expected event: wait
+    /// for confirmation depth, then verify the block is still canonical
+    async fn check_claim(&self, chain_id: u64, tx_hash: H256) -> RelayerResult<()> {
+        let Some((tx_id, nonce, original_tx, claim_block_number, claim_block_hash)) =
+            self.tracked.get(&(chain_id, tx_hash)).and_then(|entry| {
+                entry.claim.as_ref().map(|claim| {
+                    (
+                        entry.tx_id,
+                        entry.nonce,
+                        entry.original_tx.clone(),
+                        claim.block_number,
+                        claim.block_hash,
+                    )
+                })
+            })
+        else {
+            return Ok(());
+        };
+
+        let provider = self.chain_manager.get_provider(chain_id)?;
+        let current_block = provider.get_block_number().await?;
+        let confirmations = current_block.saturating_sub(claim_block_number);
+
+        if confirmations < provider.confirmation_blocks() {
+            return Ok(());
+        }
+
+        let still_canonical = matches!(
+            provider.get_block(claim_block_number).await?,
+            Some(block) if block.hash == Some(claim_block_hash)
+        );
+
+        if still_canonical {
+            self.state_manager.mark_resolved(&tx_id).await?;
+            self.tracked.remove(&(chain_id, tx_hash));
+            info!(
+                "Chain {} tx {:?} resolved ({} confirmations)",
+                chain_id, tx_hash, confirmations
+            );
+            return Ok(());
+        }
+
+        warn!(
+            "Chain {} tx {:?} reorged out of block {}; resyncing nonce {} and resubmitting",
+            chain_id, tx_hash, claim_block_number, nonce
+        );
+        crate::metrics::record_tx_reorged_out(chain_id);
+        self.nonce_manager.sync(chain_id, &provider).await?;
+
+        let new_tx_hash = self
+            .tx_sender
+            .resubmit_at_current_price(chain_id, nonce, &original_tx)
+            .await?;
+        self.nonce_manager
+            .mark_pending(chain_id, nonce, &format!("{:?}", new_tx_hash))
+            .await?;
+
+        self.tracked.remove(&(chain_id, tx_hash));
+        self.tracked.insert(
+            (chain_id, new_tx_hash),
+            Submission {
+                tx_id,
+                nonce,
+                original_tx,
+                claim: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Build (or reuse) the event parser for a chain
+    fn event_parser(&self, chain_id: u64) -> RelayerResult<Arc<EventParser>> {
+        if let Some(parser) = self.event_parsers.get(&chain_id) {
+            return Ok(parser.clone());
+        }
+
+        let provider = self.chain_manager.get_provider(chain_id)?;
+        let parser = Arc::new(EventParser::new(&provider.contract_address())?.with_chain_id(chain_id));
+        self.event_parsers.insert(chain_id, parser.clone());
+        Ok(parser)
+    }
+
+    /// Number of submissions currently followed to resolution (for diagnostics)
+    pub fn tracked_count(&self) -> usize {
+        self.tracked.len()
+    }
+}
+
+/// Whether a decoded event represents the expected resolution of `tx_id`
+fn event_resolves(event: &ContractEvent, tx_id: &[u8; 32]) -> bool {
+    matches!(
+        event,
+        ContractEvent::DependencyResolved { tx_id: id, .. } if id == tx_id
+    ) || matches!(
+        event,
+        ContractEvent::TransactionReady { tx_id: id, .. } if id == tx_id
+    )
+}
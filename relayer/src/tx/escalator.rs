@@ -0,0 +1,343 @@
+//! Gas escalator for transactions stuck in the mempool
+//!
+//! Watches nonces marked pending by `NonceManager` and, once a transaction
+//! has sat unconfirmed for longer than `RelayerConfig::gas_escalation_interval_secs`
+//! (or, under `EscalationSchedule::PerBlock`, for at least
+//! `gas_escalation_interval_blocks` new blocks), resubmits it at the same
+//! nonce with a higher gas price. The multiplier grows geometrically
+//! (never by less than the 10% minimum replacement bump most nodes enforce)
+//! and is clamped to `max_gas_price_gwei`, until a receipt appears, the
+//! nonce is released, or the configured attempt cap is reached.
+
+use super::gas::GasEstimator;
+use super::nonce::NonceManager;
+use super::sender::TransactionSender;
+use crate::chain::{ChainManager, GasPrice};
+use crate::config::{EscalationSchedule, RelayerConfig};
+
+use dashmap::DashMap;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::H256;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+/// In-flight escalation state for a single (chain_id, nonce)
+struct Escalation {
+    original_tx: TypedTransaction,
+    /// Gas price of the very first submission, before any escalation
+    first_gas: GasPrice,
+    current_gas: GasPrice,
+    tx_hash: H256,
+    /// Block height observed when this nonce was first submitted
+    first_seen_block: u64,
+    last_bump: Instant,
+    /// Chain height at the last bump (or at tracking start), for the
+    /// `PerBlock` schedule
+    last_bump_block: u64,
+    steps: u32,
+}
+
+/// Resolved escalation settings for a chain, after applying any per-chain
+/// `ChainConfig` override over the `RelayerConfig` default
+struct EscalationSettings {
+    factor_permille: u64,
+    max_multiplier_permille: u64,
+    max_attempts: u32,
+    schedule: EscalationSchedule,
+    interval_blocks: u64,
+}
+
+/// Escalates gas prices for stuck transactions on a fixed schedule
+pub struct GasEscalator {
+    chain_manager: Arc<ChainManager>,
+    nonce_manager: Arc<NonceManager>,
+    tx_sender: Arc<TransactionSender>,
+    gas_estimator: GasEstimator,
+    config: RelayerConfig,
+    tracked: DashMap<(u64, u64), Escalation>,
+    shutdown: Arc<RwLock<bool>>,
+}
+
+impl GasEscalator {
+    /// Create a new gas escalator
+    pub fn new(
+        chain_manager: Arc<ChainManager>,
+        nonce_manager: Arc<NonceManager>,
+        tx_sender: Arc<TransactionSender>,
+        config: RelayerConfig,
+    ) -> Self {
+        Self {
+            chain_manager,
+            nonce_manager,
+            gas_estimator: GasEstimator::from_config(&config),
+            tx_sender,
+            config,
+            tracked: DashMap::new(),
+            shutdown: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    /// Start tracking a freshly submitted transaction for escalation
+    pub fn track(
+        &self,
+        chain_id: u64,
+        nonce: u64,
+        tx_hash: H256,
+        original_tx: TypedTransaction,
+        gas_price: GasPrice,
+        first_seen_block: u64,
+    ) {
+        self.tracked.insert(
+            (chain_id, nonce),
+            Escalation {
+                original_tx,
+                first_gas: gas_price.clone(),
+                current_gas: gas_price,
+                tx_hash,
+                first_seen_block,
+                last_bump: Instant::now(),
+                last_bump_block: first_seen_block,
+                steps: 0,
+            },
+        );
+    }
+
+    /// Stop tracking a nonce (receipt confirmed, replaced, or released elsewhere)
+    pub fn untrack(&self, chain_id: u64, nonce: u64) {
+        self.tracked.remove(&(chain_id, nonce));
+    }
+
+    /// Run the escalation loop until `stop()` is called
+    pub async fn run(&self) {
+        let poll_interval = Duration::from_secs(self.config.gas_escalation_interval_secs.max(1));
+        let mut ticker = tokio::time::interval(poll_interval);
+
+        loop {
+            if *self.shutdown.read().await {
+                break;
+            }
+            ticker.tick().await;
+            self.tick().await;
+        }
+    }
+
+    /// Stop the escalation loop
+    pub async fn stop(&self) {
+        *self.shutdown.write().await = true;
+    }
+
+    async fn tick(&self) {
+        let due_interval = Duration::from_secs(self.config.gas_escalation_interval_secs.max(1));
+
+        // Fetch each tracked chain's current height once per tick, rather
+        // than once per entry, for the `PerBlock` schedule.
+        let mut chain_ids: Vec<u64> = self.tracked.iter().map(|e| e.key().0).collect();
+        chain_ids.sort_unstable();
+        chain_ids.dedup();
+
+        let mut block_heights = HashMap::with_capacity(chain_ids.len());
+        for chain_id in chain_ids {
+            if let Ok(provider) = self.chain_manager.get_provider(chain_id) {
+                if let Ok(height) = provider.get_block_number().await {
+                    block_heights.insert(chain_id, height);
+                }
+            }
+        }
+
+        let due: Vec<(u64, u64)> = self
+            .tracked
+            .iter()
+            .filter(|e| {
+                let (chain_id, _) = *e.key();
+                match self.escalation_settings(chain_id).schedule {
+                    EscalationSchedule::EverySecs => e.last_bump.elapsed() >= due_interval,
+                    EscalationSchedule::PerBlock => {
+                        let interval_blocks = self.escalation_settings(chain_id).interval_blocks;
+                        block_heights
+                            .get(&chain_id)
+                            .map(|&height| height.saturating_sub(e.last_bump_block) >= interval_blocks)
+                            .unwrap_or(false)
+                    }
+                }
+            })
+            .map(|e| *e.key())
+            .collect();
+
+        for (chain_id, nonce) in due {
+            if let Err(e) = self.escalate(chain_id, nonce).await {
+                warn!(
+                    "Gas escalation failed for chain {} nonce {}: {}",
+                    chain_id, nonce, e
+                );
+            }
+        }
+    }
+
+    async fn escalate(&self, chain_id: u64, nonce: u64) -> crate::error::RelayerResult<()> {
+        // The transaction confirmed or was released since we last looked - stop tracking it
+        if !self.nonce_manager.is_pending(chain_id, nonce).await {
+            self.untrack(chain_id, nonce);
+            return Ok(());
+        }
+
+        let (original_tx, current_gas, tx_hash, steps, first_seen_block, first_gas) =
+            match self.tracked.get(&(chain_id, nonce)) {
+                Some(entry) => (
+                    entry.original_tx.clone(),
+                    entry.current_gas.clone(),
+                    entry.tx_hash,
+                    entry.steps,
+                    entry.first_seen_block,
+                    entry.first_gas.clone(),
+                ),
+                None => return Ok(()),
+            };
+
+        // Double-check against the chain directly: the nonce manager only
+        // learns about confirmations once something else polls for receipts,
+        // so a transaction can already be mined while still "pending" here.
+        let provider = self.chain_manager.get_provider(chain_id).ok();
+        if let Some(provider) = &provider {
+            if let Ok(Some(_)) = provider.get_transaction_receipt(tx_hash).await {
+                self.untrack(chain_id, nonce);
+                return Ok(());
+            }
+        }
+        let current_block = match &provider {
+            Some(provider) => provider.get_block_number().await.unwrap_or(first_seen_block),
+            None => first_seen_block,
+        };
+
+        let settings = self.escalation_settings(chain_id);
+
+        if steps >= settings.max_attempts {
+            warn!(
+                "Chain {} nonce {} hit the gas escalation attempt cap ({}), holding at current price",
+                chain_id, nonce, settings.max_attempts
+            );
+            crate::metrics::record_gas_escalation_ceiling(chain_id);
+            return Ok(());
+        }
+
+        // `current_gas` already reflects every prior bump compounded
+        // geometrically (see `GasEstimator::escalate_gas_price`), so compare
+        // its actual ratio over the first submission price against the
+        // ceiling rather than a linear `factor * steps` approximation, which
+        // trips far short of the configured ceiling for any multi-step
+        // escalation.
+        let current_multiplier_permille = if first_gas.primary_wei().is_zero() {
+            0
+        } else {
+            (current_gas.primary_wei().saturating_mul(ethers::types::U256::from(1000u64))
+                / first_gas.primary_wei())
+            .as_u64()
+        };
+        if current_multiplier_permille >= settings.max_multiplier_permille {
+            warn!(
+                "Chain {} nonce {} hit the gas escalation multiplier cap, holding at current price",
+                chain_id, nonce
+            );
+            crate::metrics::record_gas_escalation_ceiling(chain_id);
+            return Ok(());
+        }
+
+        let max_gas_price_gwei = provider
+            .as_ref()
+            .map(|p| p.config().max_gas_price_gwei)
+            .unwrap_or(u64::MAX);
+        let (new_gas, price_ceiling_reached) =
+            self.gas_estimator
+                .escalate_gas_price(&current_gas, settings.factor_permille, max_gas_price_gwei);
+
+        if price_ceiling_reached {
+            warn!(
+                "Chain {} nonce {} gas escalation hit max_gas_price_gwei ({}), holding at the ceiling",
+                chain_id, nonce, max_gas_price_gwei
+            );
+            crate::metrics::record_gas_escalation_ceiling(chain_id);
+            // Already at the ceiling: a replacement at the same price would
+            // only be rejected as underpriced, so hold instead of resubmitting.
+            if new_gas.primary_wei() <= current_gas.primary_wei() {
+                return Ok(());
+            }
+        }
+
+        let new_tx_hash = self
+            .tx_sender
+            .resubmit_with_gas(chain_id, nonce, &original_tx, &new_gas)
+            .await?;
+
+        if let Some(mut entry) = self.tracked.get_mut(&(chain_id, nonce)) {
+            entry.current_gas = new_gas;
+            entry.tx_hash = new_tx_hash;
+            entry.last_bump = Instant::now();
+            entry.last_bump_block = current_block;
+            entry.steps += 1;
+        }
+
+        self.nonce_manager
+            .mark_pending(chain_id, nonce, &format!("{:?}", new_tx_hash))
+            .await?;
+
+        crate::metrics::record_gas_escalation(chain_id);
+        debug!(
+            "Escalated gas for chain {} nonce {} (first seen at block {}, original price {:?}, step {}): {:?}",
+            chain_id,
+            nonce,
+            first_seen_block,
+            first_gas,
+            steps + 1,
+            new_tx_hash
+        );
+
+        Ok(())
+    }
+
+    /// Resolve the escalation bump factor, multiplier ceiling, max attempts,
+    /// and schedule for a chain: per-chain `ChainConfig` overrides win,
+    /// falling back to the global `RelayerConfig` defaults.
+    fn escalation_settings(&self, chain_id: u64) -> EscalationSettings {
+        let chain_config = self
+            .chain_manager
+            .get_provider(chain_id)
+            .ok()
+            .map(|p| p.config());
+
+        let factor_permille = chain_config
+            .as_ref()
+            .and_then(|c| c.gas_escalation_factor_permille)
+            .unwrap_or(self.config.gas_escalation_factor_permille);
+        let max_multiplier_permille = chain_config
+            .as_ref()
+            .and_then(|c| c.gas_escalation_max_multiplier_permille)
+            .unwrap_or(self.config.gas_escalation_max_multiplier_permille);
+        let max_attempts = chain_config
+            .as_ref()
+            .and_then(|c| c.gas_escalation_max_attempts)
+            .unwrap_or(self.config.gas_escalation_max_attempts);
+        let schedule = chain_config
+            .as_ref()
+            .and_then(|c| c.gas_escalation_schedule)
+            .unwrap_or(self.config.gas_escalation_schedule);
+        let interval_blocks = chain_config
+            .as_ref()
+            .and_then(|c| c.gas_escalation_interval_blocks)
+            .unwrap_or(self.config.gas_escalation_interval_blocks);
+
+        EscalationSettings {
+            factor_permille,
+            max_multiplier_permille,
+            max_attempts,
+            schedule,
+            interval_blocks,
+        }
+    }
+
+    /// Number of transactions currently tracked for escalation (for diagnostics)
+    pub fn tracked_count(&self) -> usize {
+        self.tracked.len()
+    }
+}
@@ -1,11 +1,27 @@
 //! Gas estimation and optimization for different chain types
 
 use crate::chain::{ChainProvider, GasPrice};
-use crate::config::GasPriceStrategy;
-use crate::error::{RelayerError, RelayerResult};
+use crate::config::{GasPriceStrategy, RelayerConfig};
+use crate::error::RelayerResult;
+use crate::tx::gas_oracle::MedianGasOracle;
 
+use dashmap::DashMap;
 use ethers::types::U256;
-use tracing::debug;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// EIP-1559's minimum replacement bump: 10% of both `maxFeePerGas` and
+/// `maxPriorityFeePerGas`, expressed as a per-mille addition to 1000
+const MIN_REPLACEMENT_BUMP_PERMILLE: u64 = 100;
+
+/// Result of `GasEstimator::estimate_resolve_gas`: the L2 gas limit plus any
+/// L1 data-availability surcharge, which on Optimism and Arbitrum is charged
+/// separately from L2 execution and often dominates total cost
+#[derive(Debug, Clone, Copy)]
+pub struct L2GasBreakdown {
+    pub gas_limit: U256,
+    pub l1_data_fee: U256,
+}
 
 /// Gas estimator for transactions
 pub struct GasEstimator {
@@ -13,6 +29,14 @@ pub struct GasEstimator {
     gas_limit_buffer_percent: u64,
     /// Buffer percentage for gas price
     gas_price_buffer_percent: u64,
+    /// Floor for the fee-history-derived priority fee, in wei
+    priority_fee_floor: U256,
+    /// Ceiling for the fee-history-derived priority fee, in wei
+    priority_fee_ceiling: U256,
+    /// Lazily-built `MedianGasOracle` per chain, keyed by chain ID, so the
+    /// oracle HTTP clients persist across calls instead of being rebuilt
+    /// (and reconnected) on every `get_gas_price`
+    oracles: DashMap<u64, Arc<MedianGasOracle>>,
 }
 
 impl GasEstimator {
@@ -21,21 +45,80 @@ impl GasEstimator {
         Self {
             gas_limit_buffer_percent: 20,
             gas_price_buffer_percent: 10,
+            priority_fee_floor: U256::zero(),
+            priority_fee_ceiling: U256::MAX,
+            oracles: DashMap::new(),
+        }
+    }
+
+    /// Create a gas estimator that clamps fee-history priority fees per `RelayerConfig`
+    pub fn from_config(config: &RelayerConfig) -> Self {
+        Self {
+            priority_fee_floor: U256::from(config.priority_fee_floor_gwei) * U256::from(1_000_000_000u64),
+            priority_fee_ceiling: U256::from(config.priority_fee_ceiling_gwei) * U256::from(1_000_000_000u64),
+            ..Self::new()
         }
     }
 
     /// Estimate gas for a resolve_dependency call
-    pub async fn estimate_resolve_gas(&self, provider: &ChainProvider) -> RelayerResult<U256> {
+    ///
+    /// `calldata` is the exact bytes the call will submit (selector + tx id);
+    /// on Optimism and Arbitrum it's also used to size the L1 data-availability
+    /// surcharge, which otherwise dominates total cost and can't be derived
+    /// from the L2 gas limit alone.
+    pub async fn estimate_resolve_gas(
+        &self,
+        provider: &ChainProvider,
+        calldata: &[u8],
+    ) -> RelayerResult<L2GasBreakdown> {
         // Base gas for resolve_dependency is around 50-100k
         // We add a buffer for safety
         let base_gas = U256::from(100_000);
         let buffer = base_gas * self.gas_limit_buffer_percent / 100;
-        Ok(base_gas + buffer)
+        let gas_limit = base_gas + buffer;
+
+        let l1_data_fee = match provider.l1_data_fee(calldata).await {
+            Ok(fee) => fee,
+            Err(e) => {
+                warn!(
+                    "L1 data fee unavailable for chain {}: {}, treating as zero",
+                    provider.chain_id(),
+                    e
+                );
+                U256::zero()
+            }
+        };
+
+        Ok(L2GasBreakdown {
+            gas_limit,
+            l1_data_fee,
+        })
     }
 
     /// Get optimized gas price for a chain
+    ///
+    /// For EIP-1559 chains, delegates to `ChainProvider::estimate_eip1559_fees`
+    /// (the `eth_feeHistory`-driven estimate with proper base-fee-update-rule
+    /// prediction), clamping the priority fee to the configured floor/ceiling;
+    /// other strategies use `ChainProvider::get_gas_price` directly.
     pub async fn get_gas_price(&self, provider: &ChainProvider) -> RelayerResult<GasPrice> {
-        let gas_price = provider.get_gas_price().await?;
+        if let Some(price) = self.oracle_gas_price(provider).await {
+            return Ok(price);
+        }
+
+        let gas_price = if matches!(
+            provider.gas_price_strategy(),
+            GasPriceStrategy::Eip1559 | GasPriceStrategy::Optimism
+        ) {
+            let (max_fee_per_gas, priority_fee) = provider.estimate_eip1559_fees().await?;
+            GasPrice::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas: priority_fee
+                    .clamp(self.priority_fee_floor, self.priority_fee_ceiling),
+            }
+        } else {
+            provider.get_gas_price().await?
+        };
 
         // Add buffer to gas price
         let buffered = match gas_price {
@@ -61,6 +144,56 @@ impl GasEstimator {
         Ok(buffered)
     }
 
+    /// Query `ChainConfig::gas_oracles`, if any are configured for this
+    /// chain, and return the aggregated price - or `None` if none are
+    /// configured or all of them failed, so the caller falls back to the
+    /// on-chain path.
+    async fn oracle_gas_price(&self, provider: &ChainProvider) -> Option<GasPrice> {
+        let chain_config = provider.config();
+        if chain_config.gas_oracles.is_empty() {
+            return None;
+        }
+
+        let oracle = self
+            .oracles
+            .entry(chain_config.chain_id)
+            .or_insert_with(|| Arc::new(MedianGasOracle::from_configs(&chain_config.gas_oracles)))
+            .clone();
+
+        match oracle.fetch(chain_config.gas_category).await {
+            Ok(price_wei) => {
+                let price = Self::oracle_price_to_gas_price(provider, price_wei);
+                debug!(
+                    "Oracle gas price for chain {}: {:?}",
+                    chain_config.chain_id, price
+                );
+                Some(price)
+            }
+            Err(e) => {
+                warn!(
+                    "All gas oracles failed for chain {}: {}, falling back to on-chain estimation",
+                    chain_config.chain_id, e
+                );
+                None
+            }
+        }
+    }
+
+    /// Oracle trackers report a single target price rather than the
+    /// base-fee/priority-fee split EIP-1559 needs, so on those chains treat
+    /// it as the max fee and take a conservative tenth of it as the
+    /// priority fee - the same ballpark as `ChainProvider::estimate_eip1559_fees`'s
+    /// predicted-base-fee-plus-reward estimate.
+    fn oracle_price_to_gas_price(provider: &ChainProvider, price_wei: U256) -> GasPrice {
+        match provider.gas_price_strategy() {
+            GasPriceStrategy::Eip1559 | GasPriceStrategy::Optimism => GasPrice::Eip1559 {
+                max_fee_per_gas: price_wei,
+                max_priority_fee_per_gas: price_wei / 10,
+            },
+            GasPriceStrategy::Legacy | GasPriceStrategy::Arbitrum => GasPrice::Legacy(price_wei),
+        }
+    }
+
     /// Calculate speed-up gas price for stuck transaction
     pub fn speed_up_gas_price(&self, current: &GasPrice, factor: u64) -> GasPrice {
         match current {
@@ -77,12 +210,62 @@ impl GasEstimator {
         }
     }
 
-    /// Calculate total cost in wei
-    pub fn calculate_cost(gas_limit: U256, gas_price: &GasPrice) -> U256 {
-        match gas_price {
+    /// Calculate the gas price for an escalation step
+    ///
+    /// Unlike `speed_up_gas_price` (a single flat percentage bump), this takes
+    /// a per-mille factor so schedules like a 12.5% bump per step (1125)
+    /// can be expressed precisely. Mirrors ethers'
+    /// `EscalatingPending` policy: the bump never drops below the EIP-1559
+    /// minimum of 10% on both `maxFeePerGas` and `maxPriorityFeePerGas`, even
+    /// if `factor_permille` would otherwise compute a smaller one. The result
+    /// is clamped to `max_gas_price_gwei`; the returned `bool` is `true` when
+    /// that clamp changed the price, so the caller can alert instead of
+    /// quietly resubmitting at the same ceiling forever.
+    pub fn escalate_gas_price(
+        &self,
+        current: &GasPrice,
+        factor_permille: u64,
+        max_gas_price_gwei: u64,
+    ) -> (GasPrice, bool) {
+        let effective_permille = factor_permille.max(1000 + MIN_REPLACEMENT_BUMP_PERMILLE);
+        let max_price_wei = U256::from(max_gas_price_gwei) * U256::from(1_000_000_000u64);
+
+        let escalated = match current {
+            GasPrice::Legacy(price) => GasPrice::Legacy(*price * effective_permille / 1000),
+            GasPrice::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => GasPrice::Eip1559 {
+                max_fee_per_gas: *max_fee_per_gas * effective_permille / 1000,
+                max_priority_fee_per_gas: *max_priority_fee_per_gas * effective_permille / 1000,
+            },
+        };
+
+        match escalated {
+            GasPrice::Legacy(price) if price > max_price_wei => {
+                (GasPrice::Legacy(max_price_wei), true)
+            }
+            GasPrice::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas } if max_fee_per_gas > max_price_wei => {
+                (
+                    GasPrice::Eip1559 {
+                        max_fee_per_gas: max_price_wei,
+                        max_priority_fee_per_gas: std::cmp::min(max_priority_fee_per_gas, max_price_wei),
+                    },
+                    true,
+                )
+            }
+            other => (other, false),
+        }
+    }
+
+    /// Calculate total cost in wei, including any L1 data-availability
+    /// surcharge (zero on chains that don't charge for one separately)
+    pub fn calculate_cost(gas_limit: U256, gas_price: &GasPrice, l1_data_fee: U256) -> U256 {
+        let execution_cost = match gas_price {
             GasPrice::Legacy(price) => gas_limit * *price,
             GasPrice::Eip1559 { max_fee_per_gas, .. } => gas_limit * *max_fee_per_gas,
-        }
+        };
+        execution_cost + l1_data_fee
     }
 }
 
@@ -91,3 +274,14 @@ impl Default for GasEstimator {
         Self::new()
     }
 }
+
+/// Median of a slice of U256 values (sorts in place)
+pub(crate) fn median(values: &mut [U256]) -> U256 {
+    values.sort();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2
+    } else {
+        values[mid]
+    }
+}
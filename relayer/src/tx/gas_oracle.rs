@@ -0,0 +1,260 @@
+//! Pluggable external gas-price oracles
+//!
+//! `ChainProvider::get_gas_price` (and by extension `GasEstimator`) only
+//! ever asks the chain's own node for a price via `eth_gasPrice` or fee
+//! history, which can be stale or manipulable, especially during a gas
+//! spike on a congested mempool the node itself is part of. This module
+//! lets a chain instead (or additionally, as a preference) query one or
+//! more external trackers through [`GasOracle`], aggregated via
+//! [`MedianGasOracle`] so a single bad or slow source can't skew the price
+//! `GasEstimator::get_gas_price` ultimately uses.
+
+use crate::config::{GasCategory, GasOracleKind, OracleConfig};
+use crate::error::{RelayerError, RelayerResult};
+use crate::tx::gas::median;
+
+use async_trait::async_trait;
+use ethers::types::U256;
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::warn;
+
+/// Timeout applied to each individual oracle query
+const ORACLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Queries a single external gas-price tracker for a given speed tier,
+/// returning the price in wei.
+#[async_trait]
+pub trait GasOracle: Send + Sync {
+    async fn fetch(&self, category: GasCategory) -> RelayerResult<U256>;
+
+    /// Short human-readable description, for logs.
+    fn describe(&self) -> String;
+}
+
+#[derive(Debug, Deserialize)]
+struct EtherchainResponse {
+    #[serde(rename = "safeLow")]
+    safe_low: f64,
+    standard: f64,
+    fast: f64,
+    fastest: f64,
+}
+
+/// An Etherchain-style tracker returning `{safeLow, standard, fast,
+/// fastest, currentBaseFee}` gwei floats from a single GET.
+pub struct EtherchainOracle {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl EtherchainOracle {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl GasOracle for EtherchainOracle {
+    async fn fetch(&self, category: GasCategory) -> RelayerResult<U256> {
+        let response: EtherchainResponse = self
+            .client
+            .get(&self.url)
+            .timeout(ORACLE_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| RelayerError::GasEstimation(format!("etherchain oracle request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| RelayerError::GasEstimation(format!("etherchain oracle response invalid: {}", e)))?;
+
+        let gwei = match category {
+            GasCategory::SafeLow => response.safe_low,
+            GasCategory::Standard => response.standard,
+            GasCategory::Fast => response.fast,
+            GasCategory::Fastest => response.fastest,
+        };
+
+        Ok(gwei_to_wei(gwei))
+    }
+
+    fn describe(&self) -> String {
+        format!("etherchain ({})", self.url)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BlocknativeResponse {
+    #[serde(rename = "blockPrices")]
+    block_prices: Vec<BlocknativeBlockPrice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlocknativeBlockPrice {
+    #[serde(rename = "estimatedPrices")]
+    estimated_prices: Vec<BlocknativeEstimatedPrice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlocknativeEstimatedPrice {
+    confidence: u32,
+    price: f64,
+}
+
+/// A Blocknative-style tracker (`GET /gasprices/blockprices`), whose
+/// response reports gwei estimates per confidence percentage rather than
+/// named tiers - mapped onto [`GasCategory`] by the confidence level
+/// closest to each tier's intent.
+pub struct BlocknativeOracle {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+}
+
+impl BlocknativeOracle {
+    pub fn new(url: String, api_key: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+            api_key,
+        }
+    }
+
+    fn target_confidence(category: GasCategory) -> u32 {
+        match category {
+            GasCategory::SafeLow => 70,
+            GasCategory::Standard => 90,
+            GasCategory::Fast => 95,
+            GasCategory::Fastest => 99,
+        }
+    }
+}
+
+#[async_trait]
+impl GasOracle for BlocknativeOracle {
+    async fn fetch(&self, category: GasCategory) -> RelayerResult<U256> {
+        let mut request = self.client.get(&self.url).timeout(ORACLE_TIMEOUT);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", api_key);
+        }
+
+        let response: BlocknativeResponse = request
+            .send()
+            .await
+            .map_err(|e| RelayerError::GasEstimation(format!("blocknative oracle request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| RelayerError::GasEstimation(format!("blocknative oracle response invalid: {}", e)))?;
+
+        let estimated_prices = &response
+            .block_prices
+            .first()
+            .ok_or_else(|| RelayerError::GasEstimation("blocknative oracle returned no block prices".to_string()))?
+            .estimated_prices;
+
+        let target = Self::target_confidence(category);
+        let closest = estimated_prices
+            .iter()
+            .min_by_key(|p| (p.confidence as i64 - target as i64).abs())
+            .ok_or_else(|| {
+                RelayerError::GasEstimation("blocknative oracle returned no estimated prices".to_string())
+            })?;
+
+        Ok(gwei_to_wei(closest.price))
+    }
+
+    fn describe(&self) -> String {
+        format!("blocknative ({})", self.url)
+    }
+}
+
+/// Queries every configured oracle concurrently and returns the
+/// (weight-adjusted) median of the ones that didn't error or time out, so
+/// a single bad or slow source can't skew the result. Each oracle's weight
+/// repeats its price that many times in the pool (a weight of 0 counts
+/// once) before taking the median - a simple way to bias the aggregate without floating-point interpolation.
+pub struct MedianGasOracle {
+    oracles: Vec<(Box<dyn GasOracle>, u32)>,
+}
+
+impl MedianGasOracle {
+    pub fn new(oracles: Vec<(Box<dyn GasOracle>, u32)>) -> Self {
+        Self { oracles }
+    }
+
+    /// Build a `MedianGasOracle` from `ChainConfig::gas_oracles`.
+    pub fn from_configs(configs: &[OracleConfig]) -> Self {
+        let oracles = configs
+            .iter()
+            .map(|config| {
+                let oracle: Box<dyn GasOracle> = match config.kind {
+                    GasOracleKind::Etherchain => Box::new(EtherchainOracle::new(config.url.clone())),
+                    GasOracleKind::Blocknative => {
+                        Box::new(BlocknativeOracle::new(config.url.clone(), config.api_key.clone()))
+                    }
+                };
+                (oracle, config.weight)
+            })
+            .collect();
+        Self { oracles }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.oracles.is_empty()
+    }
+
+    pub async fn fetch(&self, category: GasCategory) -> RelayerResult<U256> {
+        if self.oracles.is_empty() {
+            return Err(RelayerError::GasEstimation("no gas oracles configured".to_string()));
+        }
+
+        let queries = self.oracles.iter().map(|(oracle, weight)| async move {
+            match tokio::time::timeout(ORACLE_TIMEOUT, oracle.fetch(category)).await {
+                Ok(Ok(price)) => Some((price, *weight)),
+                Ok(Err(e)) => {
+                    warn!("Gas oracle {} failed: {}", oracle.describe(), e);
+                    None
+                }
+                Err(_) => {
+                    warn!("Gas oracle {} timed out", oracle.describe());
+                    None
+                }
+            }
+        });
+
+        let results = futures::future::join_all(queries).await;
+
+        let mut weighted: Vec<U256> = Vec::new();
+        for (price, weight) in results.into_iter().flatten() {
+            for _ in 0..weight.max(1) {
+                weighted.push(price);
+            }
+        }
+
+        if weighted.is_empty() {
+            return Err(RelayerError::GasEstimation("all configured gas oracles failed".to_string()));
+        }
+
+        Ok(median(&mut weighted))
+    }
+}
+
+/// Convert a floating-point gwei price (the unit every tracker API above
+/// returns) to wei, saturating rather than panicking on an out-of-range
+/// `as u128` cast - upstream trackers occasionally return a negative, NaN,
+/// or absurdly large value during an outage.
+fn gwei_to_wei(gwei: f64) -> U256 {
+    if !gwei.is_finite() || gwei <= 0.0 {
+        return U256::zero();
+    }
+
+    let wei = gwei * 1_000_000_000.0;
+    if wei >= u128::MAX as f64 {
+        U256::MAX
+    } else {
+        U256::from(wei as u128)
+    }
+}
@@ -1,9 +1,19 @@
 //! Transaction submission module with nonce management and gas optimization
 
+mod confirmation;
+mod escalator;
 mod gas;
+pub mod gas_oracle;
 mod nonce;
+mod scheduler;
 mod sender;
+mod signer;
 
-pub use gas::GasEstimator;
+pub use confirmation::ConfirmationTracker;
+pub use escalator::GasEscalator;
+pub use gas::{GasEstimator, L2GasBreakdown};
+pub use gas_oracle::{GasOracle, MedianGasOracle};
 pub use nonce::NonceManager;
+pub use scheduler::Scheduler;
 pub use sender::TransactionSender;
+pub use signer::TxSigner;
@@ -224,6 +224,34 @@ impl NonceManager {
         Ok(stuck)
     }
 
+    /// Check whether a nonce is still tracked as pending (not confirmed or released)
+    pub async fn is_pending(&self, chain_id: u64, nonce: u64) -> bool {
+        match self.chain_state.get(&chain_id) {
+            Some(state) => state.lock().await.pending.contains_key(&nonce),
+            None => false,
+        }
+    }
+
+    /// Check whether a nonce has already been confirmed on-chain, i.e. it is
+    /// at or below the last confirmed nonce and thus has nothing left to
+    /// recover. Distinguishes a confirmed nonce from one that was dropped
+    /// from the mempool entirely (neither pending nor confirmed).
+    pub async fn is_confirmed(&self, chain_id: u64, nonce: u64) -> bool {
+        match self.chain_state.get(&chain_id) {
+            Some(state) => nonce <= state.lock().await.confirmed,
+            None => false,
+        }
+    }
+
+    /// Get the tx hash currently recorded as pending for a nonce, if any.
+    /// Lets a tracked submission detect that it was superseded by a
+    /// replacement (e.g. a gas escalation bump) before it got a receipt.
+    pub async fn pending_tx_hash(&self, chain_id: u64, nonce: u64) -> Option<String> {
+        let state = self.chain_state.get(&chain_id)?;
+        let state = state.lock().await;
+        state.pending.get(&nonce).map(|h| h.clone())
+    }
+
     /// Fetch nonce from chain
     async fn fetch_nonce(&self, provider: &ChainProvider) -> RelayerResult<u64> {
         let nonce = provider
@@ -240,12 +268,9 @@ impl NonceManager {
 
     /// Get pending count for a chain
     pub async fn pending_count(&self, chain_id: u64) -> usize {
-        self.chain_state
-            .get(&chain_id)
-            .map(|s| {
-                // Can't await in map, so use blocking
-                0 // Simplified - in production we'd use proper async
-            })
-            .unwrap_or(0)
+        match self.chain_state.get(&chain_id) {
+            Some(state) => state.lock().await.pending.len(),
+            None => 0,
+        }
     }
 }
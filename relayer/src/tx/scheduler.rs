@@ -0,0 +1,192 @@
+//! Per-chain nonce-managed submission scheduler
+//!
+//! `CoordinationEngine::process_pending` hands each tick's ready set to a
+//! `Scheduler` instead of submitting transactions independently. The
+//! scheduler topologically sorts the batch by intra-batch `dependency_id`
+//! edges and submits it serially through `TransactionSender`, so nonces -
+//! still allocated one at a time by `NonceManager::get_nonce` - come out in
+//! dependency-respecting order without the scheduler tracking any nonce
+//! state of its own.
+//!
+//! It also remembers the nonces it most recently assigned per chain so a
+//! later `recover_gaps` call can notice a transaction dropped from the
+//! mempool (neither pending nor confirmed - as opposed to replaced or
+//! reorged out, both already handled by `ConfirmationTracker`) and re-queue
+//! everything scheduled behind it.
+
+use super::sender::TransactionSender;
+use crate::coordination::PendingTransaction;
+use crate::error::RelayerResult;
+
+use dashmap::DashMap;
+use ethers::types::H256;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Per-chain nonce-ordered batch submitter
+pub struct Scheduler {
+    tx_sender: Arc<TransactionSender>,
+    /// Nonces assigned by `submit_batch` per chain, oldest first, kept until
+    /// `recover_gaps` finds them confirmed or re-queues them
+    scheduled: DashMap<u64, Mutex<VecDeque<(u64, PendingTransaction)>>>,
+}
+
+impl Scheduler {
+    pub fn new(tx_sender: Arc<TransactionSender>) -> Self {
+        Self {
+            tx_sender,
+            scheduled: DashMap::new(),
+        }
+    }
+
+    /// Submit a batch of ready transactions for a single chain in
+    /// dependency order, assigning each the next sequential nonce.
+    ///
+    /// Submission is serial rather than concurrent: a failure partway
+    /// through still leaves every nonce allocated so far correctly
+    /// accounted for, and `TransactionSender::send_with_retry` releases a
+    /// nonce back to `NonceManager` once it gives up after its retries.
+    pub async fn submit_batch(
+        &self,
+        chain_id: u64,
+        txs: Vec<PendingTransaction>,
+    ) -> Vec<(PendingTransaction, RelayerResult<H256>)> {
+        if txs.is_empty() {
+            return Vec::new();
+        }
+
+        let ordered = Self::topo_order(txs);
+        let mut results = Vec::with_capacity(ordered.len());
+        let mut assigned = VecDeque::with_capacity(ordered.len());
+
+        for tx in ordered {
+            match self.tx_sender.submit_resolve(&tx).await {
+                Ok((nonce, tx_hash)) => {
+                    assigned.push_back((nonce, tx.clone()));
+                    results.push((tx, Ok(tx_hash)));
+                }
+                Err(e) => {
+                    results.push((tx, Err(e)));
+                }
+            }
+        }
+
+        if !assigned.is_empty() {
+            let slot = self
+                .scheduled
+                .entry(chain_id)
+                .or_insert_with(|| Mutex::new(VecDeque::new()));
+            slot.lock().await.extend(assigned);
+        }
+
+        results
+    }
+
+    /// Order `txs` so that a transaction appears after the transaction it
+    /// depends on, when that dependency is itself part of the same batch.
+    /// Dependencies outside the batch (already submitted in an earlier
+    /// tick, or not part of this chain's ready set) don't constrain order
+    /// here - they're handled by the dependency graph only admitting a
+    /// transaction to the ready set once its dependency is resolved.
+    fn topo_order(txs: Vec<PendingTransaction>) -> Vec<PendingTransaction> {
+        use std::collections::HashMap;
+
+        let index_by_tx_id: HashMap<[u8; 32], usize> = txs
+            .iter()
+            .enumerate()
+            .map(|(i, tx)| (tx.tx_id, i))
+            .collect();
+
+        let n = txs.len();
+        let mut indegree = vec![0usize; n];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+        for (i, tx) in txs.iter().enumerate() {
+            if let Some(dep_id) = tx.dependency_id {
+                if let Some(&dep_idx) = index_by_tx_id.get(&dep_id) {
+                    dependents[dep_idx].push(i);
+                    indegree[i] += 1;
+                }
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..n).filter(|&i| indegree[i] == 0).collect();
+        let mut order = Vec::with_capacity(n);
+
+        while let Some(i) = ready.pop_front() {
+            order.push(i);
+            for &dependent in &dependents[i] {
+                indegree[dependent] -= 1;
+                if indegree[dependent] == 0 {
+                    ready.push_back(dependent);
+                }
+            }
+        }
+
+        // Intra-batch dependency edges mirror the dependency graph, which is
+        // acyclic by construction, so this only triggers if that invariant
+        // is ever violated. Fall back to submission order for anything left
+        // out rather than dropping transactions.
+        if order.len() < n {
+            let ordered: std::collections::HashSet<usize> = order.iter().copied().collect();
+            order.extend((0..n).filter(|i| !ordered.contains(i)));
+        }
+
+        let mut txs: Vec<Option<PendingTransaction>> = txs.into_iter().map(Some).collect();
+        order.into_iter().map(|i| txs[i].take().unwrap()).collect()
+    }
+
+    /// Re-queue transactions scheduled behind a nonce that was dropped from
+    /// the mempool entirely (neither pending nor confirmed on `chain_id`).
+    /// Returns the transactions that need resubmitting, in their original
+    /// order, for the caller to feed back into the priority queue or submit
+    /// directly.
+    pub async fn recover_gaps(&self, chain_id: u64) -> Vec<PendingTransaction> {
+        let Some(slot) = self.scheduled.get(&chain_id) else {
+            return Vec::new();
+        };
+        let nonce_manager = self.tx_sender.nonce_manager();
+        let mut batch = slot.lock().await;
+
+        // Drop anything already confirmed; there's nothing left to track.
+        let mut open = VecDeque::with_capacity(batch.len());
+        for (nonce, tx) in batch.drain(..) {
+            if !nonce_manager.is_confirmed(chain_id, nonce).await {
+                open.push_back((nonce, tx));
+            }
+        }
+
+        let mut gap_at = None;
+        for (i, (nonce, _)) in open.iter().enumerate() {
+            if !nonce_manager.is_pending(chain_id, *nonce).await {
+                gap_at = Some(i);
+                break;
+            }
+        }
+
+        let Some(i) = gap_at else {
+            *batch = open;
+            return Vec::new();
+        };
+
+        let dropped_nonce = open[i].0;
+        let to_requeue: Vec<PendingTransaction> = open
+            .split_off(i)
+            .into_iter()
+            .map(|(_, tx)| tx)
+            .collect();
+        *batch = open;
+
+        warn!(
+            "Chain {} nonce {} dropped from mempool; re-queuing {} transaction(s) scheduled behind it",
+            chain_id,
+            dropped_nonce,
+            to_requeue.len()
+        );
+        crate::metrics::record_nonce_gap_recovered(chain_id);
+
+        to_requeue
+    }
+}
@@ -1,17 +1,20 @@
 //! Transaction sender with retry logic and stuck transaction handling
 
+use super::confirmation::ConfirmationTracker;
+use super::escalator::GasEscalator;
 use super::gas::GasEstimator;
 use super::nonce::NonceManager;
+use super::signer::TxSigner;
 use crate::chain::{ChainManager, GasPrice};
-use crate::config::RelayerConfig;
+use crate::config::{RelayerConfig, WalletConfig};
 use crate::coordination::PendingTransaction;
 use crate::error::{RelayerError, RelayerResult};
 use crate::state::StateManager;
 
 use ethers::prelude::*;
-use ethers::signers::{LocalWallet, Signer};
 use ethers::types::transaction::eip2718::TypedTransaction;
-use std::sync::Arc;
+use ethers::types::transaction::eip2930::{AccessList, Eip2930TransactionRequest};
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 use tokio::time::timeout;
 use tracing::{debug, error, info, warn};
@@ -26,10 +29,14 @@ pub struct TransactionSender {
     nonce_manager: Arc<NonceManager>,
     /// Gas estimator
     gas_estimator: GasEstimator,
-    /// Wallet for signing
-    wallet: LocalWallet,
+    /// Signer for outgoing transactions
+    signer: TxSigner,
     /// Configuration
     config: RelayerConfig,
+    /// Gas escalator, attached once by the coordination engine at startup
+    escalator: OnceLock<Arc<GasEscalator>>,
+    /// Confirmation tracker, attached once by the coordination engine at startup
+    confirmation_tracker: OnceLock<Arc<ConfirmationTracker>>,
 }
 
 impl TransactionSender {
@@ -38,10 +45,10 @@ impl TransactionSender {
         chain_manager: Arc<ChainManager>,
         state_manager: Arc<StateManager>,
         config: RelayerConfig,
+        wallet_config: &WalletConfig,
     ) -> RelayerResult<Self> {
-        // Load wallet from environment or keystore
-        let wallet = Self::load_wallet().await?;
-        let wallet_address = wallet.address();
+        let signer = TxSigner::load(wallet_config).await?;
+        let wallet_address = signer.address();
 
         info!("Transaction sender initialized with wallet: {:?}", wallet_address);
 
@@ -60,60 +67,82 @@ impl TransactionSender {
             chain_manager,
             state_manager,
             nonce_manager,
-            gas_estimator: GasEstimator::new(),
-            wallet,
+            gas_estimator: GasEstimator::from_config(&config),
+            signer,
             config,
+            escalator: OnceLock::new(),
+            confirmation_tracker: OnceLock::new(),
         })
     }
 
-    /// Load wallet from environment or keystore
-    async fn load_wallet() -> RelayerResult<LocalWallet> {
-        // Try environment variable first (dev mode)
-        if let Ok(key) = std::env::var("RELAYER_PRIVATE_KEY") {
-            return key
-                .parse::<LocalWallet>()
-                .map_err(|e| RelayerError::Wallet(format!("Invalid private key: {}", e)));
-        }
+    /// Attach a gas escalator so every newly submitted transaction gets
+    /// tracked for automatic fee bumps. Called once during coordination
+    /// engine startup; later calls are ignored.
+    pub fn attach_escalator(&self, escalator: Arc<GasEscalator>) {
+        let _ = self.escalator.set(escalator);
+    }
+
+    /// Attach a confirmation tracker so every newly submitted transaction is
+    /// followed through to a reorg-safe resolution. Called once during
+    /// coordination engine startup; later calls are ignored.
+    pub fn attach_confirmation_tracker(&self, tracker: Arc<ConfirmationTracker>) {
+        let _ = self.confirmation_tracker.set(tracker);
+    }
 
-        // Try keystore
-        // In production, we'd use encrypted keystore with password prompt
-        Err(RelayerError::Wallet(
-            "No wallet configured. Set RELAYER_PRIVATE_KEY or configure keystore".to_string(),
-        ))
+    /// Access the nonce manager (used by the gas escalator and confirmation tracker)
+    pub(crate) fn nonce_manager(&self) -> &Arc<NonceManager> {
+        &self.nonce_manager
     }
 
-    /// Submit a resolve_dependency transaction
+    /// Submit a resolve_dependency transaction, returning the nonce it was
+    /// assigned alongside the broadcast tx hash so callers that submit a
+    /// dependency-ordered batch (see `Scheduler`) can track it for gap
+    /// recovery.
     pub async fn submit_resolve(
         &self,
         pending_tx: &PendingTransaction,
-    ) -> RelayerResult<H256> {
+    ) -> RelayerResult<(u64, H256)> {
         let chain_id = pending_tx.target_chain;
         let provider = self.chain_manager.get_provider(chain_id)?;
 
         // Get nonce
         let nonce = self.nonce_manager.get_nonce(chain_id).await?;
 
-        // Estimate gas
-        let gas_limit = self.gas_estimator.estimate_resolve_gas(&provider).await?;
+        // Estimate gas, including any L1 data-availability surcharge for the
+        // exact calldata this call will submit
+        let calldata = Self::resolve_calldata(&pending_tx.tx_id);
+        let gas_breakdown = self
+            .gas_estimator
+            .estimate_resolve_gas(&provider, &calldata)
+            .await?;
         let gas_price = self.gas_estimator.get_gas_price(&provider).await?;
 
         // Build transaction
         let tx = self.build_resolve_tx(
             &provider,
-            &pending_tx.tx_id,
+            &calldata,
             nonce,
-            gas_limit,
+            gas_breakdown.gas_limit,
             &gas_price,
-        )?;
+        ).await?;
 
         // Sign and send with retry
-        let tx_hash = self.send_with_retry(chain_id, tx, nonce).await?;
+        let tx_hash = self.send_with_retry(chain_id, tx.clone(), nonce).await?;
 
         // Record submission
         self.nonce_manager
             .mark_pending(chain_id, nonce, &format!("{:?}", tx_hash))
             .await?;
 
+        if let Some(tracker) = self.confirmation_tracker.get() {
+            tracker.track(chain_id, nonce, tx_hash, pending_tx.tx_id, tx.clone());
+        }
+
+        if let Some(escalator) = self.escalator.get() {
+            let first_seen_block = provider.get_block_number().await.unwrap_or(0);
+            escalator.track(chain_id, nonce, tx_hash, tx, gas_price, first_seen_block);
+        }
+
         self.state_manager
             .record_submission(
                 &pending_tx.tx_id,
@@ -122,16 +151,32 @@ impl TransactionSender {
             )
             .await?;
 
+        if let Err(e) = self
+            .state_manager
+            .enqueue_submission_job(&pending_tx.tx_id, chain_id)
+            .await
+        {
+            warn!(
+                "Failed to enqueue durable submission job for {}: {}",
+                hex::encode(pending_tx.tx_id),
+                e
+            );
+        }
+
         crate::metrics::record_tx_submitted(chain_id);
 
-        Ok(tx_hash)
+        Ok((nonce, tx_hash))
     }
 
     /// Build resolve_dependency transaction
-    fn build_resolve_tx(
+    ///
+    /// When `RelayerConfig::enable_access_lists` is set, attaches an EIP-2930
+    /// access list obtained from `eth_createAccessList` if the node estimates
+    /// lower gas usage with it than without; otherwise the plain tx is sent.
+    async fn build_resolve_tx(
         &self,
         provider: &crate::chain::ChainProvider,
-        tx_id: &[u8; 32],
+        calldata: &[u8],
         nonce: u64,
         gas_limit: U256,
         gas_price: &GasPrice,
@@ -141,14 +186,9 @@ impl TransactionSender {
             .parse()
             .map_err(|e| RelayerError::Config(format!("Invalid contract address: {}", e)))?;
 
-        // Encode function call: resolve_dependency(bytes32 tx_id)
-        // Function selector: keccak256("resolve_dependency(bytes32)")[:4]
-        let mut data = vec![0x12, 0x34, 0x56, 0x78]; // Placeholder selector
-        data.extend_from_slice(tx_id);
-
         let mut tx = TransactionRequest::new()
             .to(contract_address)
-            .data(data)
+            .data(calldata.to_vec())
             .nonce(nonce)
             .gas(gas_limit);
 
@@ -173,7 +213,73 @@ impl TransactionSender {
             }
         };
 
-        Ok(typed_tx)
+        if !self.config.enable_access_lists {
+            return Ok(typed_tx);
+        }
+
+        self.with_access_list_if_cheaper(provider, typed_tx).await
+    }
+
+    /// Attach an `eth_createAccessList` result to `tx` if it lowers the node's
+    /// gas estimate; otherwise return `tx` unchanged. Never fails the caller -
+    /// any error from the access list RPC just skips the optimization.
+    async fn with_access_list_if_cheaper(
+        &self,
+        provider: &crate::chain::ChainProvider,
+        tx: TypedTransaction,
+    ) -> RelayerResult<TypedTransaction> {
+        let base_estimate = match provider.estimate_gas(&tx).await {
+            Ok(gas) => gas,
+            Err(e) => {
+                debug!("Could not estimate base gas for access list comparison: {}", e);
+                return Ok(tx);
+            }
+        };
+
+        let (access_list, _) = match provider.create_access_list(&tx).await {
+            Ok(result) => result,
+            Err(e) => {
+                debug!(
+                    "eth_createAccessList unavailable on chain {}: {}",
+                    provider.chain_id(),
+                    e
+                );
+                return Ok(tx);
+            }
+        };
+
+        if access_list.0.is_empty() {
+            return Ok(tx);
+        }
+
+        let tx_with_list = Self::attach_access_list(tx.clone(), access_list);
+
+        match provider.estimate_gas(&tx_with_list).await {
+            Ok(estimate_with_list) if estimate_with_list < base_estimate => Ok(tx_with_list),
+            _ => Ok(tx),
+        }
+    }
+
+    /// Encode a `resolve_dependency(bytes32 tx_id)` call
+    /// Function selector: keccak256("resolve_dependency(bytes32)")[:4]
+    fn resolve_calldata(tx_id: &[u8; 32]) -> Vec<u8> {
+        let mut data = vec![0x12, 0x34, 0x56, 0x78]; // Placeholder selector
+        data.extend_from_slice(tx_id);
+        data
+    }
+
+    /// Attach an access list to a typed transaction, converting bare legacy
+    /// transactions to EIP-2930 in the process
+    fn attach_access_list(tx: TypedTransaction, access_list: AccessList) -> TypedTransaction {
+        match tx {
+            TypedTransaction::Legacy(inner) => {
+                TypedTransaction::Eip2930(Eip2930TransactionRequest::new(inner, access_list))
+            }
+            TypedTransaction::Eip1559(inner) => {
+                TypedTransaction::Eip1559(inner.access_list(access_list))
+            }
+            other => other,
+        }
     }
 
     /// Send transaction with retry logic
@@ -184,21 +290,21 @@ impl TransactionSender {
         nonce: u64,
     ) -> RelayerResult<H256> {
         let provider = self.chain_manager.get_provider(chain_id)?;
-        let wallet = self.wallet.clone().with_chain_id(chain_id);
 
         let mut attempts = 0;
         let max_attempts = self.config.max_retries;
         let mut last_error = None;
+        let mut release_nonce_on_failure = true;
 
         while attempts < max_attempts {
             attempts += 1;
 
             // Sign transaction
-            let signed_tx = match wallet.sign_transaction(&tx).await {
+            let signed_tx = match self.signer.sign_transaction(&tx, chain_id).await {
                 Ok(sig) => tx.rlp_signed(&sig),
                 Err(e) => {
                     error!("Failed to sign transaction: {}", e);
-                    last_error = Some(RelayerError::Wallet(e.to_string()));
+                    last_error = Some(e);
                     continue;
                 }
             };
@@ -234,8 +340,17 @@ impl TransactionSender {
                             message: "Nonce too low".to_string(),
                         });
                     } else if error_msg.contains("replacement transaction underpriced") {
-                        warn!("Transaction underpriced, increasing gas");
-                        // Would rebuild tx with higher gas
+                        // A prior transaction at this nonce is still live in the
+                        // mempool; the gas escalator owns bumping its fee on a
+                        // schedule, so stop retrying here and leave the nonce
+                        // pending rather than releasing it out from under it.
+                        warn!(
+                            "Chain {} nonce {} replacement underpriced; deferring to gas escalator",
+                            chain_id, nonce
+                        );
+                        release_nonce_on_failure = false;
+                        last_error = Some(RelayerError::Transaction(error_msg));
+                        break;
                     } else if error_msg.contains("insufficient funds") {
                         return Err(RelayerError::InsufficientBalance {
                             chain_id,
@@ -260,14 +375,44 @@ impl TransactionSender {
             }
         }
 
-        // Release nonce on failure
-        self.nonce_manager.release_nonce(chain_id, nonce).await?;
+        // Release nonce on failure, unless something else (e.g. the gas
+        // escalator) is expected to keep driving it
+        if release_nonce_on_failure {
+            self.nonce_manager.release_nonce(chain_id, nonce).await?;
+        }
 
         Err(last_error.unwrap_or(RelayerError::Transaction(
             "Unknown error".to_string(),
         )))
     }
 
+    /// Resubmit a transaction at `nonce` with an explicit new gas price. Used
+    /// by the gas escalator to step up fees on a fixed schedule.
+    pub(crate) async fn resubmit_with_gas(
+        &self,
+        chain_id: u64,
+        nonce: u64,
+        original_tx: &TypedTransaction,
+        new_gas: &GasPrice,
+    ) -> RelayerResult<H256> {
+        let new_tx = self.rebuild_tx_with_gas(original_tx, new_gas)?;
+        self.send_with_retry(chain_id, new_tx, nonce).await
+    }
+
+    /// Resubmit a transaction at `nonce` using a freshly fetched gas price.
+    /// Used by the confirmation tracker to replace a claim invalidated by a
+    /// reorg, once the nonce has been re-synced with the chain.
+    pub(crate) async fn resubmit_at_current_price(
+        &self,
+        chain_id: u64,
+        nonce: u64,
+        original_tx: &TypedTransaction,
+    ) -> RelayerResult<H256> {
+        let provider = self.chain_manager.get_provider(chain_id)?;
+        let gas_price = self.gas_estimator.get_gas_price(&provider).await?;
+        self.resubmit_with_gas(chain_id, nonce, original_tx, &gas_price).await
+    }
+
     /// Speed up a stuck transaction
     pub async fn speed_up(
         &self,
@@ -288,7 +433,7 @@ impl TransactionSender {
         self.send_with_retry(chain_id, new_tx, nonce).await
     }
 
-    /// Rebuild transaction with new gas price
+    /// Rebuild transaction with new gas price, preserving any access list
     fn rebuild_tx_with_gas(
         &self,
         tx: &TypedTransaction,
@@ -299,8 +444,9 @@ impl TransactionSender {
         let data = tx.data().cloned();
         let nonce = tx.nonce().cloned();
         let gas = tx.gas().cloned();
+        let access_list = tx.access_list().cloned();
 
-        match gas_price {
+        let rebuilt = match gas_price {
             GasPrice::Legacy(price) => {
                 let mut new_tx = TransactionRequest::new();
                 if let Some(NameOrAddress::Address(addr)) = to {
@@ -340,7 +486,12 @@ impl TransactionSender {
                     .max_priority_fee_per_gas(*max_priority_fee_per_gas);
                 Ok(TypedTransaction::Eip1559(new_tx))
             }
-        }
+        }?;
+
+        Ok(match access_list {
+            Some(list) if !list.0.is_empty() => Self::attach_access_list(rebuilt, list),
+            _ => rebuilt,
+        })
     }
 
     /// Get wallet balance on a chain
@@ -348,7 +499,7 @@ impl TransactionSender {
         let provider = self.chain_manager.get_provider(chain_id)?;
         provider
             .http()
-            .get_balance(self.wallet.address(), None)
+            .get_balance(self.signer.address(), None)
             .await
             .map_err(|e| RelayerError::ChainConnection {
                 chain_id,
@@ -358,6 +509,6 @@ impl TransactionSender {
 
     /// Get wallet address
     pub fn wallet_address(&self) -> Address {
-        self.wallet.address()
+        self.signer.address()
     }
 }
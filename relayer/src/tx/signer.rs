@@ -0,0 +1,115 @@
+//! Pluggable transaction signer: raw private key, encrypted Web3 keystore, or
+//! a hardware Ledger wallet
+//!
+//! Selected by `WalletConfig`: `use_ledger` wins if set, otherwise
+//! `keystore_path` (password from `RELAYER_KEYSTORE_PASSWORD` or an
+//! interactive prompt), otherwise the env var named by `private_key_env`
+//! (`RELAYER_PRIVATE_KEY` if unset). All three variants sign both legacy and
+//! EIP-1559 `TypedTransaction`s and expose a stable `address()`.
+
+use crate::config::WalletConfig;
+use crate::error::{RelayerError, RelayerResult};
+
+use ethers::signers::{HDPath, Ledger, LocalWallet, Signer as EthersSigner};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::{Address, Signature};
+
+/// A transaction signer backed by one of three key sources
+pub enum TxSigner {
+    PrivateKey(LocalWallet),
+    Keystore(LocalWallet),
+    /// A Ledger's `chain_id` field only matters for the legacy EIP-155
+    /// recovery id (EIP-1559 transactions carry their own chain ID), so - like
+    /// `PrivateKey`/`Keystore` below - it's re-derived with `with_chain_id`
+    /// on every `sign_transaction` call rather than fixed at connection time,
+    /// since this relayer is multi-chain and shares one `TxSigner` across all
+    /// of them (see `TransactionSender`).
+    Ledger(Ledger),
+}
+
+impl TxSigner {
+    /// Load a signer according to `WalletConfig`
+    pub async fn load(config: &WalletConfig) -> RelayerResult<Self> {
+        if config.use_ledger {
+            return Self::load_ledger(config).await;
+        }
+
+        if let Some(path) = &config.keystore_path {
+            return Self::load_keystore(path).await;
+        }
+
+        Self::load_private_key(config)
+    }
+
+    async fn load_ledger(config: &WalletConfig) -> RelayerResult<Self> {
+        let account = config.ledger_account_index.unwrap_or(0);
+        // The chain ID passed here only seeds the connection; every
+        // `sign_transaction` call below re-derives it for the chain actually
+        // being signed for via `with_chain_id`.
+        let ledger = Ledger::new(HDPath::LedgerLive(account), 1)
+            .await
+            .map_err(|e| RelayerError::Wallet(format!("Ledger connection failed: {}", e)))?;
+
+        Ok(Self::Ledger(ledger))
+    }
+
+    async fn load_keystore(path: &str) -> RelayerResult<Self> {
+        let password = match std::env::var("RELAYER_KEYSTORE_PASSWORD") {
+            Ok(password) => password,
+            Err(_) => rpassword::prompt_password("Keystore password: ")
+                .map_err(|e| RelayerError::Wallet(format!("Failed to read password: {}", e)))?,
+        };
+
+        let wallet = LocalWallet::decrypt_keystore(path, password)
+            .map_err(|e| RelayerError::Wallet(format!("Failed to decrypt keystore: {}", e)))?;
+
+        Ok(Self::Keystore(wallet))
+    }
+
+    fn load_private_key(config: &WalletConfig) -> RelayerResult<Self> {
+        let env_var = config.private_key_env.as_deref().unwrap_or("RELAYER_PRIVATE_KEY");
+
+        let key = std::env::var(env_var).map_err(|_| {
+            RelayerError::Wallet(format!(
+                "No wallet configured: set {} or configure a keystore/Ledger",
+                env_var
+            ))
+        })?;
+
+        let wallet = key
+            .parse::<LocalWallet>()
+            .map_err(|e| RelayerError::Wallet(format!("Invalid private key: {}", e)))?;
+
+        Ok(Self::PrivateKey(wallet))
+    }
+
+    /// Stable wallet address, independent of which chain is being signed for
+    pub fn address(&self) -> Address {
+        match self {
+            TxSigner::PrivateKey(wallet) | TxSigner::Keystore(wallet) => wallet.address(),
+            TxSigner::Ledger(ledger) => ledger.address(),
+        }
+    }
+
+    /// Sign a typed transaction for the given chain
+    pub async fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+        chain_id: u64,
+    ) -> RelayerResult<Signature> {
+        match self {
+            TxSigner::PrivateKey(wallet) | TxSigner::Keystore(wallet) => wallet
+                .clone()
+                .with_chain_id(chain_id)
+                .sign_transaction(tx)
+                .await
+                .map_err(|e| RelayerError::Wallet(e.to_string())),
+            TxSigner::Ledger(ledger) => ledger
+                .clone()
+                .with_chain_id(chain_id)
+                .sign_transaction(tx)
+                .await
+                .map_err(|e| RelayerError::Wallet(e.to_string())),
+        }
+    }
+}